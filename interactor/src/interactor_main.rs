@@ -129,7 +129,7 @@ impl ContractInteract {
     }
 
     async fn escrow_succes(&mut self, token_id: String, token_nonce: u64, token_amount: BigUint<StaticApi>, 
-                    wanted_nft: TokenIdentifier<StaticApi>, wanted_nonce: u64, wanted_address: &Bech32Address) -> u32 { 
+                    wanted_nft: TokenIdentifier<StaticApi>, wanted_nonce: u64, wanted_address: &Bech32Address) -> u64 { 
         let response = self
             .interactor
             .tx()
@@ -137,7 +137,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(NumExpr("30,000,000"))
             .typed(proxy::NftEscrowContractProxy)
-            .escrow(wanted_nft, wanted_nonce, wanted_address)
+            .escrow(wanted_nft, wanted_nonce, wanted_address, MultiValueVec::<proxy::EscrowOption<StaticApi>>::new())
             .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, token_amount))
             .returns(ReturnsResultUnmanaged)
             .prepare_async()
@@ -156,7 +156,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(NumExpr("30,000,000"))
             .typed(proxy::NftEscrowContractProxy)
-            .escrow(wanted_nft, wanted_nonce, wanted_address)
+            .escrow(wanted_nft, wanted_nonce, wanted_address, MultiValueVec::<proxy::EscrowOption<StaticApi>>::new())
             .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, token_amount))
             .returns(expected_result)
             .prepare_async()
@@ -164,7 +164,7 @@ impl ContractInteract {
             .await;
     }
 
-    async fn cancel(&mut self, offer_id: u32) {
+    async fn cancel(&mut self, offer_id: u64) {
 
         self
             .interactor
@@ -181,7 +181,7 @@ impl ContractInteract {
 
     }
 
-    async fn cancel_failed(&mut self, offer_id: u32, expected_result: ExpectError<'_>) {
+    async fn cancel_failed(&mut self, offer_id: u64, expected_result: ExpectError<'_>) {
 
         self
             .interactor
@@ -198,7 +198,7 @@ impl ContractInteract {
 
     }
 
-    async fn cancel_failed_adress(&mut self, offer_id: u32, expected_result: ExpectError<'_>) {
+    async fn cancel_failed_adress(&mut self, offer_id: u64, expected_result: ExpectError<'_>) {
 
         let wallet_address = self.interactor.register_wallet(test_wallets::carol());
         self
@@ -216,7 +216,7 @@ impl ContractInteract {
 
     }
 
-    async fn accept_success(&mut self, token_id: String, token_nonce: u64, token_amount:  BigUint<StaticApi>, offer_id: u32) {
+    async fn accept_success(&mut self, token_id: String, token_nonce: u64, token_amount:  BigUint<StaticApi>, offer_id: u64) {
         let user = self.interactor.register_wallet(test_wallets::bob());
         let response = self
             .interactor
@@ -225,7 +225,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(NumExpr("30,000,000"))
             .typed(proxy::NftEscrowContractProxy)
-            .accept(offer_id)
+            .accept(offer_id, MultiValueVec::<proxy::AcceptOption<StaticApi>>::new())
             .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, BigUint::from(token_amount)))
             .returns(ReturnsResultUnmanaged)
             .prepare_async()
@@ -235,7 +235,7 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    async fn accept_fail(&mut self, token_id:  String, token_nonce: u64, token_amount:  BigUint<StaticApi>, offer_id: u32, expected_result: ExpectError<'_>) {
+    async fn accept_fail(&mut self, token_id:  String, token_nonce: u64, token_amount:  BigUint<StaticApi>, offer_id: u64, expected_result: ExpectError<'_>) {
         let response = self
             .interactor
             .tx()
@@ -243,7 +243,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(NumExpr("30,000,000"))
             .typed(proxy::NftEscrowContractProxy)
-            .accept(offer_id)
+            .accept(offer_id, MultiValueVec::<proxy::AcceptOption<StaticApi>>::new())
             .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, BigUint::from(token_amount)))
             .returns(expected_result)
             .prepare_async()
@@ -253,7 +253,7 @@ impl ContractInteract {
         println!("Result: {response:?}");
     }
 
-    async fn accept_fail_address(&mut self, token_id: String, token_nonce: u64, token_amount:u128, offer_id: u32, expected_result: ExpectError<'_>) {
+    async fn accept_fail_address(&mut self, token_id: String, token_nonce: u64, token_amount:u128, offer_id: u64, expected_result: ExpectError<'_>) {
         //let wallet_address = self.interactor.register_wallet(test_wallets::bob());
         let user = self.interactor.register_wallet(test_wallets::alice());
         let response = self
@@ -263,7 +263,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(NumExpr("30,000,000"))
             .typed(proxy::NftEscrowContractProxy)
-            .accept(offer_id)
+            .accept(offer_id, MultiValueVec::<proxy::AcceptOption<StaticApi>>::new())
             .payment((TokenIdentifier::from(token_id.as_str()), token_nonce, BigUint::from(token_amount)))
             .returns(expected_result)
             .prepare_async()
@@ -341,7 +341,7 @@ impl ContractInteract {
         println!("Result: {result_value:?}");
     }
 
-    async fn offers(&mut self, id: u32) {
+    async fn offers(&mut self, id: u64) {
         let result_value = self
             .interactor
             .query()
@@ -519,7 +519,7 @@ async fn test_accept_success() {
 async fn test_cancel_offer_not_exists() {
     let mut interact = ContractInteract::new().await;
     interact.deploy().await;
-    let offer_id = 123u32;
+    let offer_id = 123u64;
     interact.cancel_failed(offer_id, ExpectError(4, "Offer does not exist")).await;
 }
 
@@ -534,7 +534,7 @@ async fn test_cancel_offer_not_owner() {
     let wanted_nonce = 106u64;
     let ref wanted_address = Bech32Address::from_bech32_string(String::from(WANTED_ADDRESS_STRING));
     let offer_id = interact.escrow_succes(token_id, token_nonce, token_amount, wanted_nft, wanted_nonce, wanted_address).await;
-    interact.cancel_failed_adress(offer_id, ExpectError(4, "Only the offer creator can cancel it")).await;
+    interact.cancel_failed_adress(offer_id, ExpectError(4, "Only the offer creator or an approved operator can cancel it")).await;
 }
 
 #[tokio::test]
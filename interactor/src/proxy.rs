@@ -53,6 +53,25 @@ where
     }
 }
 
+#[rustfmt::skip]
+impl<Env, From, To, Gas> NftEscrowContractProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
 #[rustfmt::skip]
 impl<Env, From, To, Gas> NftEscrowContractProxyMethods<Env, From, To, Gas>
 where
@@ -66,22 +85,49 @@ where
         Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
         Arg1: ProxyArg<u64>,
         Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, EscrowOption<Env::Api>>>,
     >(
         self,
         wanted_nft: Arg0,
         wanted_nonce: Arg1,
         wanted_address: Arg2,
-    ) -> TxTypedCall<Env, From, To, (), Gas, u32> {
+        options: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
         self.wrapped_tx
             .raw_call("escrow")
             .argument(&wanted_nft)
             .argument(&wanted_nonce)
             .argument(&wanted_address)
+            .argument(&options)
+            .original_result()
+    }
+
+    pub fn escrow_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, EscrowOption<Env::Api>>>,
+    >(
+        self,
+        creator: Arg0,
+        wanted_nft: Arg1,
+        wanted_nonce: Arg2,
+        wanted_address: Arg3,
+        options: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("escrowFor")
+            .argument(&creator)
+            .argument(&wanted_nft)
+            .argument(&wanted_nonce)
+            .argument(&wanted_address)
+            .argument(&options)
             .original_result()
     }
 
     pub fn cancel<
-        Arg0: ProxyArg<u32>,
+        Arg0: ProxyArg<u64>,
     >(
         self,
         offer_id: Arg0,
@@ -93,15 +139,170 @@ where
             .original_result()
     }
 
+    pub fn cancel_many<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        offer_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelMany")
+            .argument(&offer_ids)
+            .original_result()
+    }
+
+    pub fn cancel_all(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelAll")
+            .original_result()
+    }
+
+    pub fn transfer_offer<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        offer_id: Arg0,
+        new_creator: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transferOffer")
+            .argument(&offer_id)
+            .argument(&new_creator)
+            .original_result()
+    }
+
     pub fn accept<
-        Arg0: ProxyArg<u32>,
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, AcceptOption<Env::Api>>>,
     >(
         self,
         offer_id: Arg0,
+        options: Arg1,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("accept")
             .argument(&offer_id)
+            .argument(&options)
+            .original_result()
+    }
+
+    pub fn claim_with_preimage<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        offer_id: Arg0,
+        preimage: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimWithPreimage")
+            .argument(&offer_id)
+            .argument(&preimage)
+            .original_result()
+    }
+
+    pub fn approve_offer_action<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ApprovalAction>,
+    >(
+        self,
+        offer_id: Arg0,
+        action: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveOfferAction")
+            .argument(&offer_id)
+            .argument(&action)
+            .original_result()
+    }
+
+    pub fn approve_arbitration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveArbitration")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn reject_arbitration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rejectArbitration")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    /// Lets either party unwind a dispute the arbiter never settled. The arbiter fee goes back to 
+    /// the creator's bond. 
+    pub fn expire_arbitration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("expireArbitration")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn decline<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("decline")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    /// Returns the escrowed NFTs of offers. Wishlist entries, bids, loans and rentals have their 
+    /// own emergency endpoints. 
+    pub fn emergency_withdraw<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        batch_size: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyWithdraw")
+            .argument(&batch_size)
+            .original_result()
+    }
+
+    pub fn migrate_offer_ids<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        batch_size: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrateOfferIds")
+            .argument(&batch_size)
             .original_result()
     }
 
@@ -110,7 +311,7 @@ where
     >(
         self,
         address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, Offer<Env::Api>>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, Offer<Env::Api>>>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("getCreatedOffers")
@@ -123,7 +324,7 @@ where
     >(
         self,
         address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, Offer<Env::Api>>>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, Offer<Env::Api>>>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("getWantedOffers")
@@ -131,12 +332,75 @@ where
             .original_result()
     }
 
+    pub fn get_offer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<Offer<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOffer")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn get_offers<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, u64>>,
+    >(
+        self,
+        offer_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u64, Option<Offer<Env::Api>>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOffers")
+            .argument(&offer_ids)
+            .original_result()
+    }
+
+    pub fn offer_status<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OfferStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("offerStatus")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    /// Sums what the contract owes and reports every token it holds less of. Live offers are 
+    /// covered for the given ID range, with their locked deposits, fees and arbiter fees. The 
+    /// listed addresses are covered for their bonds, referral rewards, claimable payouts, custody, 
+    /// wishlist entries, bids, loans, lender repayments and rentals. Collected fees are always 
+    /// covered. 
+    pub fn get_solvency_report<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        from_offer_id: Arg0,
+        to_offer_id: Arg1,
+        addresses: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Discrepancy<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSolvencyReport")
+            .argument(&from_offer_id)
+            .argument(&to_offer_id)
+            .argument(&addresses)
+            .original_result()
+    }
+
     pub fn created_offers<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
         self,
         address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u32>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("created_offers")
@@ -149,7 +413,7 @@ where
     >(
         self,
         address: Arg0,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u32>> {
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_call("wanted_offers")
@@ -158,7 +422,7 @@ where
     }
 
     pub fn offers<
-        Arg0: ProxyArg<u32>,
+        Arg0: ProxyArg<u64>,
     >(
         self,
         id: Arg0,
@@ -169,18 +433,1205 @@ where
             .argument(&id)
             .original_result()
     }
-}
 
-#[type_abi]
-#[derive(TopEncode, TopDecode, Debug)]
-pub struct Offer<Api>
-where
-    Api: ManagedTypeApi,
-{
-    pub creator: ManagedAddress<Api>,
-    pub nft: TokenIdentifier<Api>,
-    pub nonce: u64,
-    pub wanted_nft: TokenIdentifier<Api>,
-    pub wanted_nonce: u64,
-    pub wanted_address: ManagedAddress<Api>,
+    pub fn offer_delivery<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Delivery<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferDelivery")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn offer_wanted_attributes<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferWantedAttributes")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn offer_arbitration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Arbitration<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferArbitration")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn pending_acceptance<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PendingAcceptance<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingAcceptance")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn offer_hashlock<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Hashlock<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferHashlock")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn offer_accepters<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferAccepters")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn offer_approval_policy<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ApprovalPolicy<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferApprovalPolicy")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn offer_approvals<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ApprovalAction>,
+    >(
+        self,
+        offer_id: Arg0,
+        action: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferApprovals")
+            .argument(&offer_id)
+            .argument(&action)
+            .original_result()
+    }
+
+    pub fn offer_counterparty_commitment<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferCounterpartyCommitment")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn set_deferred_payouts<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDeferredPayouts")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    pub fn claim<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        max_items: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claim")
+            .argument(&max_items)
+            .original_result()
+    }
+
+    pub fn get_claimable_payouts<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimablePayouts")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn deferred_payouts<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasDeferredPayouts")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn set_offer_deposit<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOfferDeposit")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_open_offers_limits<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        max_created_offers: Arg0,
+        max_wanted_offers: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOpenOffersLimits")
+            .argument(&max_created_offers)
+            .argument(&max_wanted_offers)
+            .original_result()
+    }
+
+    pub fn deposit_bond(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositBond")
+            .original_result()
+    }
+
+    pub fn withdraw_bond(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawBond")
+            .original_result()
+    }
+
+    pub fn offer_deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferDeposit")
+            .original_result()
+    }
+
+    pub fn max_created_offers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxCreatedOffers")
+            .original_result()
+    }
+
+    pub fn max_wanted_offers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxWantedOffers")
+            .original_result()
+    }
+
+    pub fn bond_balance<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBondBalance")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn locked_deposit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLockedDeposit")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn approve_operator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        operator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveOperator")
+            .argument(&operator)
+            .original_result()
+    }
+
+    pub fn revoke_operator<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        operator: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeOperator")
+            .argument(&operator)
+            .original_result()
+    }
+
+    pub fn get_operators<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOperators")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn deposit_custody(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositCustody")
+            .original_result()
+    }
+
+    pub fn withdraw_custody<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        nft: Arg0,
+        nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawCustody")
+            .argument(&nft)
+            .argument(&nonce)
+            .original_result()
+    }
+
+    pub fn cancel_signed_offer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelSignedOffer")
+            .argument(&offer_nonce)
+            .original_result()
+    }
+
+    pub fn fill_signed_offer<
+        Arg0: ProxyArg<SignedOffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        signed_offer: Arg0,
+        signature: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fillSignedOffer")
+            .argument(&signed_offer)
+            .argument(&signature)
+            .original_result()
+    }
+
+    pub fn get_custody<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCustody")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn used_offer_nonce<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        creator: Arg0,
+        offer_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isSignedOfferNonceUsed")
+            .argument(&creator)
+            .argument(&offer_nonce)
+            .original_result()
+    }
+
+    pub fn announce_emergency(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("announceEmergency")
+            .original_result()
+    }
+
+    pub fn cancel_emergency(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelEmergency")
+            .original_result()
+    }
+
+    pub fn emergency_unlock_timestamp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEmergencyUnlockTimestamp")
+            .original_result()
+    }
+
+    pub fn emergency_cursor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEmergencyCursor")
+            .original_result()
+    }
+
+    pub fn get_trader_stats<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TraderStats> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTraderStats")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn set_swap_fee<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSwapFee")
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn set_referral_rate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        referrer: Arg0,
+        rate: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setReferralRate")
+            .argument(&referrer)
+            .argument(&rate)
+            .original_result()
+    }
+
+    pub fn withdraw_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawFees")
+            .original_result()
+    }
+
+    pub fn claim_referral_rewards(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimReferralRewards")
+            .original_result()
+    }
+
+    pub fn swap_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSwapFee")
+            .original_result()
+    }
+
+    pub fn referral_rate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        referrer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferralRate")
+            .argument(&referrer)
+            .original_result()
+    }
+
+    pub fn referral_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        referrer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReferralRewards")
+            .argument(&referrer)
+            .original_result()
+    }
+
+    pub fn collected_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCollectedFees")
+            .original_result()
+    }
+
+    pub fn locked_fee<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLockedFee")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn offer_referrer<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        offer_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferReferrer")
+            .argument(&offer_id)
+            .original_result()
+    }
+
+    pub fn add_wishlist_entry<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        wanted_nft: Arg0,
+        wanted_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u32> {
+        self.wrapped_tx
+            .raw_call("addWishlistEntry")
+            .argument(&wanted_nft)
+            .argument(&wanted_nonce)
+            .original_result()
+    }
+
+    pub fn remove_wishlist_entry<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        entry_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeWishlistEntry")
+            .argument(&entry_id)
+            .original_result()
+    }
+
+    pub fn emergency_return_wishlist<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        batch_size: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyReturnWishlist")
+            .argument(&batch_size)
+            .original_result()
+    }
+
+    pub fn get_wishlist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, WishlistEntry<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWishlist")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn wishlist_emergency_cursor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWishlistEmergencyCursor")
+            .original_result()
+    }
+
+    pub fn place_bid<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        collection: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u32> {
+        self.wrapped_tx
+            .raw_call("placeBid")
+            .argument(&collection)
+            .original_result()
+    }
+
+    pub fn cancel_bid<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        bid_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelBid")
+            .argument(&bid_id)
+            .original_result()
+    }
+
+    pub fn fill_bid<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        bid_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fillBid")
+            .argument(&bid_id)
+            .original_result()
+    }
+
+    pub fn emergency_return_bids<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        batch_size: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyReturnBids")
+            .argument(&batch_size)
+            .original_result()
+    }
+
+    pub fn get_bids<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+    >(
+        self,
+        collection: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, Bid<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBids")
+            .argument(&collection)
+            .original_result()
+    }
+
+    pub fn get_bidder_bids<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        bidder: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, Bid<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBidderBids")
+            .argument(&bidder)
+            .original_result()
+    }
+
+    pub fn bids_emergency_cursor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBidsEmergencyCursor")
+            .original_result()
+    }
+
+    pub fn request_loan<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        token: Arg0,
+        amount: Arg1,
+        repayment: Arg2,
+        duration: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u32> {
+        self.wrapped_tx
+            .raw_call("requestLoan")
+            .argument(&token)
+            .argument(&amount)
+            .argument(&repayment)
+            .argument(&duration)
+            .original_result()
+    }
+
+    pub fn cancel_loan_request<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        loan_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelLoanRequest")
+            .argument(&loan_id)
+            .original_result()
+    }
+
+    pub fn fund_loan<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        loan_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundLoan")
+            .argument(&loan_id)
+            .original_result()
+    }
+
+    pub fn repay_loan<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        loan_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("repayLoan")
+            .argument(&loan_id)
+            .original_result()
+    }
+
+    pub fn claim_collateral<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        loan_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimCollateral")
+            .argument(&loan_id)
+            .original_result()
+    }
+
+    pub fn claim_repayment<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRepayment")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Funded loans are left in place, the borrower can still repay them and the lender can still 
+    /// claim the collateral once they are overdue. 
+    pub fn emergency_return_loans<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        batch_size: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyReturnLoans")
+            .argument(&batch_size)
+            .original_result()
+    }
+
+    pub fn get_borrower_loans<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        borrower: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, Loan<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBorrowerLoans")
+            .argument(&borrower)
+            .original_result()
+    }
+
+    pub fn get_lender_repayments<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        lender: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLenderRepayments")
+            .argument(&lender)
+            .original_result()
+    }
+
+    pub fn loans<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        loan_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Loan<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLoan")
+            .argument(&loan_id)
+            .original_result()
+    }
+
+    pub fn loans_emergency_cursor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLoansEmergencyCursor")
+            .original_result()
+    }
+
+    pub fn list_rental<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        price_per_period: Arg1,
+        period: Arg2,
+        collateral: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u32> {
+        self.wrapped_tx
+            .raw_call("listRental")
+            .argument(&token)
+            .argument(&price_per_period)
+            .argument(&period)
+            .argument(&collateral)
+            .original_result()
+    }
+
+    pub fn delist_rental<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        rental_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("delistRental")
+            .argument(&rental_id)
+            .original_result()
+    }
+
+    pub fn rent<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        rental_id: Arg0,
+        periods: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("rent")
+            .argument(&rental_id)
+            .argument(&periods)
+            .original_result()
+    }
+
+    pub fn return_rental<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        rental_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("returnRental")
+            .argument(&rental_id)
+            .original_result()
+    }
+
+    pub fn claim_rental_collateral<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        rental_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimRentalCollateral")
+            .argument(&rental_id)
+            .original_result()
+    }
+
+    /// Rented NFTs are with their renters, so those rentals are left to be returned or to have 
+    /// their collateral claimed. 
+    pub fn emergency_return_rentals<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        batch_size: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyReturnRentals")
+            .argument(&batch_size)
+            .original_result()
+    }
+
+    pub fn get_owner_rentals<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, Rental<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOwnerRentals")
+            .argument(&owner)
+            .original_result()
+    }
+
+    pub fn rentals<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        rental_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Rental<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRental")
+            .argument(&rental_id)
+            .original_result()
+    }
+
+    pub fn rentals_emergency_cursor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRentalsEmergencyCursor")
+            .original_result()
+    }
+
+    pub fn offer_id_migration_cursor(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOfferIdMigrationCursor")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum EscrowOption<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Delivery(Delivery<Api>),
+    WantedAttributes(ManagedBuffer<Api>),
+    Memo(ManagedBuffer<Api>),
+    Referrer(ManagedAddress<Api>),
+    Arbitration(Arbitration<Api>),
+    Hashlock(Hashlock<Api>),
+    CounterpartyCommitment(ManagedByteArray<Api, 32usize>),
+    Accepters(ManagedVec<Api, ManagedAddress<Api>>),
+    Approvers(ApprovalPolicy<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct Delivery<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub destination: ManagedAddress<Api>,
+    pub function: ManagedBuffer<Api>,
+    pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+    pub gas_limit: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct Arbitration<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub arbiter: ManagedAddress<Api>,
+    pub fee: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct Hashlock<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub hash: ManagedByteArray<Api, 32usize>,
+    pub deadline: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct ApprovalPolicy<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub approvers: ManagedVec<Api, ManagedAddress<Api>>,
+    pub threshold: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum AcceptOption<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Delivery(Delivery<Api>),
+    Referrer(ManagedAddress<Api>),
+    Salt(ManagedBuffer<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum ApprovalAction {
+    Accept,
+    Cancel,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct Offer<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub creator: ManagedAddress<Api>,
+    pub nft: TokenIdentifier<Api>,
+    pub nonce: u64,
+    pub wanted_nft: TokenIdentifier<Api>,
+    pub wanted_nonce: u64,
+    pub wanted_address: ManagedAddress<Api>,
+    pub memo: ManagedBuffer<Api>,
+    pub status: OfferStatus,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum OfferStatus {
+    Open,
+    Accepted,
+    Cancelled,
+    Declined,
+    Expired,
+    Disputed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub enum Discrepancy<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Shortfall(TokenIdentifier<Api>, u64, BigUint<Api>),
+    MissingCreatedIndex(u64),
+    MissingWantedIndex(u64),
+    DanglingCreatedIndex(ManagedAddress<Api>, u64),
+    DanglingWantedIndex(ManagedAddress<Api>, u64),
+    EgldShortfall(BigUint<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct PendingAcceptance<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub accepter: ManagedAddress<Api>,
+    pub payment: EsdtTokenPayment<Api>,
+    pub delivery: Option<Delivery<Api>>,
+    pub referrer: Option<ManagedAddress<Api>>,
+    pub deadline: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct SignedOffer<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub creator: ManagedAddress<Api>,
+    pub nft: TokenIdentifier<Api>,
+    pub nonce: u64,
+    pub wanted_nft: TokenIdentifier<Api>,
+    pub wanted_nonce: u64,
+    pub counterparty: ManagedAddress<Api>,
+    pub expiry: u64,
+    pub offer_nonce: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct TraderStats {
+    pub offers_created: u64,
+    pub offers_accepted: u64,
+    pub offers_cancelled: u64,
+    pub offers_declined: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct WishlistEntry<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub owner: ManagedAddress<Api>,
+    pub nft: TokenIdentifier<Api>,
+    pub nonce: u64,
+    pub wanted_nft: TokenIdentifier<Api>,
+    pub wanted_nonce: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct Bid<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub bidder: ManagedAddress<Api>,
+    pub nft: TokenIdentifier<Api>,
+    pub nonce: u64,
+    pub collection: TokenIdentifier<Api>,
+    pub created_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct Loan<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub borrower: ManagedAddress<Api>,
+    pub nft: TokenIdentifier<Api>,
+    pub nonce: u64,
+    pub token: EgldOrEsdtTokenIdentifier<Api>,
+    pub amount: BigUint<Api>,
+    pub repayment: BigUint<Api>,
+    pub duration: u64,
+    pub lender: Option<ManagedAddress<Api>>,
+    pub deadline: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct Rental<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub owner: ManagedAddress<Api>,
+    pub nft: TokenIdentifier<Api>,
+    pub nonce: u64,
+    pub token: EgldOrEsdtTokenIdentifier<Api>,
+    pub price_per_period: BigUint<Api>,
+    pub period: u64,
+    pub collateral: BigUint<Api>,
+    pub renter: Option<ManagedAddress<Api>>,
+    pub deadline: u64,
 }
@@ -0,0 +1,104 @@
+use multiversx_sc::imports::*;
+
+#[multiversx_sc::module]
+pub trait AntiSpamModule {
+    #[only_owner]
+    #[endpoint(setOfferDeposit)]
+    fn set_offer_deposit(&self, amount: BigUint) {
+        self.offer_deposit().set(amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setOpenOffersLimits)]
+    fn set_open_offers_limits(&self, max_created_offers: usize, max_wanted_offers: usize) {
+        self.max_created_offers().set(max_created_offers);
+        self.max_wanted_offers().set(max_wanted_offers);
+    }
+
+    #[payable("EGLD")]
+    #[endpoint(depositBond)]
+    fn deposit_bond(&self) {
+        let payment = self.call_value().egld_value().clone_value();
+
+        require!(payment > 0, "No EGLD sent");
+
+        let caller = self.blockchain().get_caller();
+
+        self.bond_balance(&caller)
+            .update(|balance| *balance += payment);
+    }
+
+    #[endpoint(withdrawBond)]
+    fn withdraw_bond(&self) {
+        let caller = self.blockchain().get_caller();
+
+        let amount = self.bond_balance(&caller).take();
+
+        require!(amount > 0, "Nothing to withdraw");
+
+        self.tx().to(&caller).egld(amount).transfer();
+    }
+
+    fn lock_offer_deposit(&self, creator: &ManagedAddress, offer_id: u64) {
+        let amount = self.offer_deposit().get();
+
+        if amount == 0 {
+            return;
+        }
+
+        let bond_mapper = self.bond_balance(creator);
+
+        require!(
+            bond_mapper.get() >= amount,
+            "Insufficient bond for the offer deposit"
+        );
+
+        bond_mapper.update(|balance| *balance -= &amount);
+        self.locked_deposit(offer_id).set(amount);
+    }
+
+    fn release_offer_deposit(&self, creator: &ManagedAddress, offer_id: u64) {
+        let amount = self.locked_deposit(offer_id).take();
+
+        if amount == 0 {
+            return;
+        }
+
+        self.bond_balance(creator)
+            .update(|balance| *balance += amount);
+    }
+
+    fn require_open_offers_limits(&self, created_offers: usize, wanted_offers: usize) {
+        let max_created_offers = self.max_created_offers().get();
+        let max_wanted_offers = self.max_wanted_offers().get();
+
+        require!(
+            max_created_offers == 0 || created_offers < max_created_offers,
+            "Too many open offers for the creator"
+        );
+        require!(
+            max_wanted_offers == 0 || wanted_offers < max_wanted_offers,
+            "Too many open offers for the wanted address"
+        );
+    }
+
+    #[view(getOfferDeposit)]
+    #[storage_mapper("offerDeposit")]
+    fn offer_deposit(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMaxCreatedOffers)]
+    #[storage_mapper("maxCreatedOffers")]
+    fn max_created_offers(&self) -> SingleValueMapper<usize>;
+
+    #[view(getMaxWantedOffers)]
+    #[storage_mapper("maxWantedOffers")]
+    fn max_wanted_offers(&self) -> SingleValueMapper<usize>;
+
+    #[view(getBondBalance)]
+    #[storage_mapper("bondBalance")]
+    fn bond_balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getLockedDeposit)]
+    #[storage_mapper("lockedDeposit")]
+    fn locked_deposit(&self, offer_id: u64) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,183 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::{claims, emergency};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct Bid<M: ManagedTypeApi> {
+    pub bidder: ManagedAddress<M>,
+    pub nft: TokenIdentifier<M>,
+    pub nonce: u64,
+    pub collection: TokenIdentifier<M>,
+    pub created_at: u64,
+}
+
+#[multiversx_sc::module]
+pub trait BidsModule: claims::ClaimsModule + emergency::EmergencyModule {
+    #[payable("*")]
+    #[endpoint(placeBid)]
+    fn place_bid(&self, collection: TokenIdentifier) -> u32 {
+        self.require_no_emergency();
+
+        let payment = self.call_value().single_esdt();
+
+        require!(
+            payment.token_nonce > 0 && payment.amount == 1,
+            "ESDT is not an NFT"
+        );
+        require!(collection.is_valid_esdt_identifier(), "Invalid collection");
+
+        let bid_id = self.last_bid_id().update(|v| {
+            *v = match v.checked_add(1) {
+                Some(next) => next,
+                None => sc_panic!("Bid ID overflow"),
+            };
+
+            *v
+        });
+
+        let bidder = self.blockchain().get_caller();
+
+        self.collection_bids(&collection).insert(bid_id);
+        self.bidder_bids(&bidder).insert(bid_id);
+
+        self.bids(bid_id).set(Bid {
+            bidder,
+            nft: payment.token_identifier,
+            nonce: payment.token_nonce,
+            collection,
+            created_at: self.blockchain().get_block_timestamp(),
+        });
+
+        bid_id
+    }
+
+    #[endpoint(cancelBid)]
+    fn cancel_bid(&self, bid_id: u32) {
+        let bids_mapper = self.bids(bid_id);
+
+        require!(!bids_mapper.is_empty(), "Bid does not exist");
+
+        let bid = bids_mapper.get();
+
+        require!(
+            bid.bidder == self.blockchain().get_caller(),
+            "Only the bidder can cancel it"
+        );
+
+        self.remove_bid(bid_id, &bid);
+
+        self.send_payment(
+            &bid.bidder,
+            EsdtTokenPayment::new(bid.nft, bid.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    #[payable("*")]
+    #[endpoint(fillBid)]
+    fn fill_bid(&self, bid_id: u32) {
+        let bids_mapper = self.bids(bid_id);
+
+        require!(!bids_mapper.is_empty(), "Bid does not exist");
+
+        let bid = bids_mapper.get();
+
+        let caller = self.blockchain().get_caller();
+
+        require!(bid.bidder != caller, "Can not fill your own bid");
+
+        let payment = self.call_value().single_esdt();
+
+        require!(
+            payment.token_identifier == bid.collection
+                && payment.token_nonce > 0
+                && payment.amount == 1,
+            "NFT is not from the bid collection"
+        );
+
+        self.remove_bid(bid_id, &bid);
+
+        self.send_payment(&bid.bidder, payment);
+        self.send_payment(
+            &caller,
+            EsdtTokenPayment::new(bid.nft, bid.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(emergencyReturnBids)]
+    fn emergency_return_bids(&self, batch_size: u32) -> bool {
+        self.emergency_scan(
+            self.bids_emergency_cursor(),
+            self.last_bid_id().get(),
+            batch_size,
+            |bid_id| {
+                let bids_mapper = self.bids(bid_id);
+
+                if bids_mapper.is_empty() {
+                    return;
+                }
+
+                let bid = bids_mapper.get();
+
+                self.remove_bid(bid_id, &bid);
+
+                self.send_payment(
+                    &bid.bidder,
+                    EsdtTokenPayment::new(bid.nft, bid.nonce, BigUint::from(1u64)),
+                );
+            },
+        )
+    }
+
+    #[view(getBids)]
+    fn get_bids(
+        &self,
+        collection: TokenIdentifier,
+    ) -> MultiValueEncoded<MultiValue2<u32, Bid<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+
+        for bid_id in self.collection_bids(&collection).iter() {
+            result.push(MultiValue2::from((bid_id, self.bids(bid_id).get())));
+        }
+
+        result
+    }
+
+    #[view(getBidderBids)]
+    fn get_bidder_bids(
+        &self,
+        bidder: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<u32, Bid<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+
+        for bid_id in self.bidder_bids(&bidder).iter() {
+            result.push(MultiValue2::from((bid_id, self.bids(bid_id).get())));
+        }
+
+        result
+    }
+
+    fn remove_bid(&self, bid_id: u32, bid: &Bid<Self::Api>) {
+        self.collection_bids(&bid.collection).remove(&bid_id);
+        self.bidder_bids(&bid.bidder).swap_remove(&bid_id);
+        self.bids(bid_id).clear();
+    }
+
+    #[storage_mapper("collectionBids")]
+    fn collection_bids(&self, collection: &TokenIdentifier) -> SetMapper<u32>;
+
+    #[storage_mapper("bidderBids")]
+    fn bidder_bids(&self, bidder: &ManagedAddress) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper("bids")]
+    fn bids(&self, bid_id: u32) -> SingleValueMapper<Bid<Self::Api>>;
+
+    #[view(getBidsEmergencyCursor)]
+    #[storage_mapper("bidsEmergencyCursor")]
+    fn bids_emergency_cursor(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("lastBidId")]
+    fn last_bid_id(&self) -> SingleValueMapper<u32>;
+}
@@ -0,0 +1,80 @@
+use multiversx_sc::imports::*;
+
+#[multiversx_sc::module]
+pub trait ClaimsModule {
+    #[endpoint(setDeferredPayouts)]
+    fn set_deferred_payouts(&self, enabled: bool) {
+        let caller = self.blockchain().get_caller();
+
+        self.deferred_payouts(&caller).set(enabled);
+    }
+
+    #[endpoint]
+    fn claim(&self, max_items: usize) -> bool {
+        require!(max_items > 0, "Invalid batch size");
+
+        let caller = self.blockchain().get_caller();
+
+        let mut claimable_mapper = self.claimable_payouts(&caller);
+
+        require!(!claimable_mapper.is_empty(), "Nothing to claim");
+
+        let mut payments = ManagedVec::new();
+
+        while payments.len() < max_items && !claimable_mapper.is_empty() {
+            let last_index = claimable_mapper.len();
+
+            payments.push(claimable_mapper.get(last_index));
+            claimable_mapper.swap_remove(last_index);
+        }
+
+        self.tx().to(&caller).payment(payments).transfer();
+
+        claimable_mapper.is_empty()
+    }
+
+    #[view(getClaimablePayouts)]
+    fn get_claimable_payouts(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        let mut result = MultiValueEncoded::new();
+
+        for payment in self.claimable_payouts(&address).iter() {
+            result.push(payment);
+        }
+
+        result
+    }
+
+    fn send_payment(&self, to: &ManagedAddress, payment: EsdtTokenPayment) {
+        if self.deferred_payouts(to).get() {
+            self.claimable_payouts(to).push(&payment);
+
+            return;
+        }
+
+        self.tx().to(to).payment(payment).transfer();
+    }
+
+    fn send_payments(&self, to: &ManagedAddress, payments: ManagedVec<EsdtTokenPayment>) {
+        if self.deferred_payouts(to).get() {
+            let mut claimable_mapper = self.claimable_payouts(to);
+
+            for payment in payments.iter() {
+                claimable_mapper.push(&payment);
+            }
+
+            return;
+        }
+
+        self.tx().to(to).payment(payments).transfer();
+    }
+
+    #[view(hasDeferredPayouts)]
+    #[storage_mapper("deferredPayouts")]
+    fn deferred_payouts(&self, address: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("claimablePayouts")]
+    fn claimable_payouts(&self, address: &ManagedAddress) -> VecMapper<EsdtTokenPayment>;
+}
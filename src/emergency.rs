@@ -0,0 +1,99 @@
+use multiversx_sc::imports::*;
+
+const EMERGENCY_TIMELOCK_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+#[multiversx_sc::module]
+pub trait EmergencyModule {
+    #[only_owner]
+    #[endpoint(announceEmergency)]
+    fn announce_emergency(&self) {
+        require!(
+            self.emergency_unlock_timestamp().is_empty(),
+            "Emergency already announced"
+        );
+
+        let unlock_timestamp = self.blockchain().get_block_timestamp() + EMERGENCY_TIMELOCK_SECONDS;
+
+        self.emergency_unlock_timestamp().set(unlock_timestamp);
+        self.emergency_cursor().set(1);
+
+        self.emergency_announced_event(unlock_timestamp);
+    }
+
+    #[only_owner]
+    #[endpoint(cancelEmergency)]
+    fn cancel_emergency(&self) {
+        require!(
+            !self.emergency_unlock_timestamp().is_empty(),
+            "Emergency not announced"
+        );
+
+        self.emergency_unlock_timestamp().clear();
+        self.emergency_cursor().clear();
+
+        self.emergency_cancelled_event();
+    }
+
+    fn require_no_emergency(&self) {
+        require!(
+            self.emergency_unlock_timestamp().is_empty(),
+            "Emergency withdrawal announced"
+        );
+    }
+
+    fn require_emergency_unlocked(&self) {
+        require!(
+            !self.emergency_unlock_timestamp().is_empty(),
+            "Emergency not announced"
+        );
+        require!(
+            self.blockchain().get_block_timestamp() >= self.emergency_unlock_timestamp().get(),
+            "Emergency timelock has not expired"
+        );
+    }
+
+    /// Walks the IDs of a store that keeps its own counter, from its cursor up to `last_id`, and
+    /// returns whether every ID has been scanned.
+    fn emergency_scan<F: FnMut(u32)>(
+        &self,
+        cursor_mapper: SingleValueMapper<u32>,
+        last_id: u32,
+        batch_size: u32,
+        mut return_entry: F,
+    ) -> bool {
+        self.require_emergency_unlocked();
+
+        let mut id = core::cmp::max(cursor_mapper.get(), 1);
+        let mut scanned = 0;
+        let mut finished = id > last_id;
+
+        while !finished && scanned < batch_size {
+            return_entry(id);
+
+            finished = id == last_id;
+            id = id.saturating_add(1);
+            scanned += 1;
+        }
+
+        cursor_mapper.set(id);
+
+        finished
+    }
+
+    #[event("emergencyAnnounced")]
+    fn emergency_announced_event(&self, #[indexed] unlock_timestamp: u64);
+
+    #[event("emergencyCancelled")]
+    fn emergency_cancelled_event(&self);
+
+    #[event("emergencyReturn")]
+    fn emergency_return_event(&self, #[indexed] offer_id: u64, #[indexed] creator: &ManagedAddress);
+
+    #[view(getEmergencyUnlockTimestamp)]
+    #[storage_mapper("emergencyUnlockTimestamp")]
+    fn emergency_unlock_timestamp(&self) -> SingleValueMapper<u64>;
+
+    #[view(getEmergencyCursor)]
+    #[storage_mapper("emergencyCursor")]
+    fn emergency_cursor(&self) -> SingleValueMapper<u64>;
+}
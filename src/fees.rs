@@ -0,0 +1,123 @@
+use multiversx_sc::imports::*;
+
+use crate::anti_spam;
+
+const MAX_BPS: u64 = 10_000;
+const MAX_REFERRAL_RATE: u64 = 5_000;
+
+#[multiversx_sc::module]
+pub trait FeesModule: anti_spam::AntiSpamModule {
+    #[only_owner]
+    #[endpoint(setSwapFee)]
+    fn set_swap_fee(&self, amount: BigUint) {
+        self.swap_fee().set(amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setReferralRate)]
+    fn set_referral_rate(&self, referrer: ManagedAddress, rate: u64) {
+        require!(rate <= MAX_REFERRAL_RATE, "Referral rate is too high");
+
+        self.referral_rate(&referrer).set(rate);
+    }
+
+    #[only_owner]
+    #[endpoint(withdrawFees)]
+    fn withdraw_fees(&self) {
+        let amount = self.collected_fees().take();
+
+        require!(amount > 0, "Nothing to withdraw");
+
+        let owner = self.blockchain().get_owner_address();
+
+        self.tx().to(&owner).egld(amount).transfer();
+    }
+
+    #[endpoint(claimReferralRewards)]
+    fn claim_referral_rewards(&self) {
+        let caller = self.blockchain().get_caller();
+
+        let amount = self.referral_rewards(&caller).take();
+
+        require!(amount > 0, "Nothing to claim");
+
+        self.tx().to(&caller).egld(amount).transfer();
+    }
+
+    fn lock_swap_fee(&self, creator: &ManagedAddress, offer_id: u64) {
+        let amount = self.swap_fee().get();
+
+        if amount == 0 {
+            return;
+        }
+
+        let bond_mapper = self.bond_balance(creator);
+
+        require!(
+            bond_mapper.get() >= amount,
+            "Insufficient bond for the swap fee"
+        );
+
+        bond_mapper.update(|balance| *balance -= &amount);
+        self.locked_fee(offer_id).set(amount);
+    }
+
+    fn release_swap_fee(&self, creator: &ManagedAddress, offer_id: u64) {
+        let amount = self.locked_fee(offer_id).take();
+
+        if amount == 0 {
+            return;
+        }
+
+        self.bond_balance(creator)
+            .update(|balance| *balance += amount);
+    }
+
+    fn charge_swap_fee(&self, offer_id: u64, referrers: &[Option<ManagedAddress>]) {
+        let fee = self.locked_fee(offer_id).take();
+
+        if fee == 0 {
+            return;
+        }
+
+        let mut remaining = fee.clone();
+
+        for referrer in referrers.iter().flatten() {
+            let share = &fee * self.referral_rate(referrer).get() / MAX_BPS;
+
+            if share == 0 {
+                continue;
+            }
+
+            remaining -= &share;
+            self.referral_rewards(referrer)
+                .update(|rewards| *rewards += share);
+        }
+
+        self.collected_fees().update(|fees| *fees += remaining);
+    }
+
+    #[view(getSwapFee)]
+    #[storage_mapper("swapFee")]
+    fn swap_fee(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getReferralRate)]
+    #[storage_mapper("referralRate")]
+    fn referral_rate(&self, referrer: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[view(getReferralRewards)]
+    #[storage_mapper("referralRewards")]
+    fn referral_rewards(&self, referrer: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getCollectedFees)]
+    #[storage_mapper("collectedFees")]
+    fn collected_fees(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getLockedFee)]
+    #[storage_mapper("lockedFee")]
+    fn locked_fee(&self, offer_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[view(getOfferReferrer)]
+    #[storage_mapper("offerReferrer")]
+    fn offer_referrer(&self, offer_id: u64) -> SingleValueMapper<ManagedAddress>;
+}
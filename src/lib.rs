@@ -3,8 +3,28 @@
 use multiversx_sc::derive_imports::*;
 use multiversx_sc::imports::*;
 
+pub mod anti_spam;
+pub mod bids;
+pub mod claims;
+pub mod emergency;
+pub mod fees;
+pub mod loans;
+pub mod offer_id_migration;
+pub mod operators;
+pub mod rentals;
+pub mod signed_offers;
+pub mod trader_stats;
+pub mod wishlist;
+
+const MAX_WANTED_ATTRIBUTES_LEN: usize = 128;
+const MAX_MEMO_LEN: usize = 256;
+const MAX_EXTRA_ACCEPTERS: usize = 4;
+const MAX_APPROVERS: usize = 10;
+const DELIVERY_CALLBACK_GAS: u64 = 3_000_000;
+const ARBITRATION_TIMEOUT_SECONDS: u64 = 7 * 24 * 60 * 60;
+
 #[type_abi]
-#[derive(TopEncode, TopDecode, Debug)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
 pub struct Offer<M: ManagedTypeApi> {
     pub creator: ManagedAddress<M>,
     pub nft: TokenIdentifier<M>,
@@ -12,44 +32,1029 @@ pub struct Offer<M: ManagedTypeApi> {
     pub wanted_nft: TokenIdentifier<M>,
     pub wanted_nonce: u64,
     pub wanted_address: ManagedAddress<M>,
+    pub memo: ManagedBuffer<M>,
+    pub status: OfferStatus,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum OfferStatus {
+    Open,
+    Accepted,
+    Cancelled,
+    Declined,
+    Expired,
+    Disputed,
+}
+
+impl OfferStatus {
+    pub fn is_live(&self) -> bool {
+        matches!(self, OfferStatus::Open | OfferStatus::Disputed)
+    }
+
+    pub fn can_transition_to(&self, next: OfferStatus) -> bool {
+        matches!(
+            (self, next),
+            (
+                OfferStatus::Open,
+                OfferStatus::Accepted
+                    | OfferStatus::Cancelled
+                    | OfferStatus::Declined
+                    | OfferStatus::Expired
+                    | OfferStatus::Disputed
+            ) | (
+                OfferStatus::Disputed,
+                OfferStatus::Accepted | OfferStatus::Cancelled
+            )
+        )
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct Delivery<M: ManagedTypeApi> {
+    pub destination: ManagedAddress<M>,
+    pub function: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    pub gas_limit: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct Arbitration<M: ManagedTypeApi> {
+    pub arbiter: ManagedAddress<M>,
+    pub fee: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct PendingAcceptance<M: ManagedTypeApi> {
+    pub accepter: ManagedAddress<M>,
+    pub payment: EsdtTokenPayment<M>,
+    pub delivery: Option<Delivery<M>>,
+    pub referrer: Option<ManagedAddress<M>>,
+    pub deadline: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct Hashlock<M: ManagedTypeApi> {
+    pub hash: ManagedByteArray<M, 32>,
+    pub deadline: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct ApprovalPolicy<M: ManagedTypeApi> {
+    pub approvers: ManagedVec<M, ManagedAddress<M>>,
+    pub threshold: u32,
 }
 
-#[multiversx_sc::contract]
-pub trait NftEscrowContract {
-    #[init]
-    fn init(&self) {}
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum ApprovalAction {
+    Accept,
+    Cancel,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum EscrowOption<M: ManagedTypeApi> {
+    Delivery(Delivery<M>),
+    WantedAttributes(ManagedBuffer<M>),
+    Memo(ManagedBuffer<M>),
+    Referrer(ManagedAddress<M>),
+    Arbitration(Arbitration<M>),
+    Hashlock(Hashlock<M>),
+    CounterpartyCommitment(ManagedByteArray<M, 32>),
+    Accepters(ManagedVec<M, ManagedAddress<M>>),
+    Approvers(ApprovalPolicy<M>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum AcceptOption<M: ManagedTypeApi> {
+    Delivery(Delivery<M>),
+    Referrer(ManagedAddress<M>),
+    Salt(ManagedBuffer<M>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub enum Discrepancy<M: ManagedTypeApi> {
+    Shortfall(TokenIdentifier<M>, u64, BigUint<M>),
+    MissingCreatedIndex(u64),
+    MissingWantedIndex(u64),
+    DanglingCreatedIndex(ManagedAddress<M>, u64),
+    DanglingWantedIndex(ManagedAddress<M>, u64),
+    EgldShortfall(BigUint<M>),
+}
+
+#[multiversx_sc::contract]
+pub trait NftEscrowContract:
+    claims::ClaimsModule
+    + anti_spam::AntiSpamModule
+    + operators::OperatorsModule
+    + signed_offers::SignedOffersModule
+    + emergency::EmergencyModule
+    + trader_stats::TraderStatsModule
+    + fees::FeesModule
+    + wishlist::WishlistModule
+    + bids::BidsModule
+    + loans::LoansModule
+    + rentals::RentalsModule
+    + offer_id_migration::OfferIdMigrationModule
+{
+    #[init]
+    fn init(&self) {
+        self.offer_ids_migrated().set(true);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {
+        self.start_offer_id_migration(self.last_offer_id().get());
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn escrow(
+        &self,
+        wanted_nft: TokenIdentifier,
+        wanted_nonce: u64,
+        wanted_address: ManagedAddress,
+        options: MultiValueEncoded<EscrowOption<Self::Api>>,
+    ) -> u64 {
+        let creator = self.blockchain().get_caller();
+
+        self.create_offer(creator, wanted_nft, wanted_nonce, wanted_address, options)
+    }
+
+    #[payable("*")]
+    #[endpoint(escrowFor)]
+    fn escrow_for(
+        &self,
+        creator: ManagedAddress,
+        wanted_nft: TokenIdentifier,
+        wanted_nonce: u64,
+        wanted_address: ManagedAddress,
+        options: MultiValueEncoded<EscrowOption<Self::Api>>,
+    ) -> u64 {
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            self.operators(&creator).contains(&caller),
+            "Caller is not an approved operator"
+        );
+
+        self.create_offer(creator, wanted_nft, wanted_nonce, wanted_address, options)
+    }
+
+    #[endpoint]
+    fn cancel(&self, offer_id: u64) {
+        let caller = self.blockchain().get_caller();
+
+        let offer = self.cancel_offer(&caller, offer_id);
+
+        self.send_payment(
+            &offer.creator,
+            EsdtTokenPayment::new(offer.nft, offer.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    #[endpoint(cancelMany)]
+    fn cancel_many(&self, offer_ids: MultiValueEncoded<u64>) {
+        let caller = self.blockchain().get_caller();
+
+        self.cancel_offers(&caller, offer_ids.to_vec());
+    }
+
+    #[endpoint(cancelAll)]
+    fn cancel_all(&self) {
+        let caller = self.blockchain().get_caller();
+
+        let offer_ids = self
+            .created_offers(&caller)
+            .iter()
+            .filter(|offer_id| self.is_cancellable(*offer_id))
+            .collect();
+
+        self.cancel_offers(&caller, offer_ids);
+    }
+
+    #[endpoint(transferOffer)]
+    fn transfer_offer(&self, offer_id: u64, new_creator: ManagedAddress) {
+        let mut offer = self.require_live_offer(offer_id);
+
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            offer.creator == caller,
+            "Only the offer creator can transfer it"
+        );
+        require!(
+            new_creator != caller,
+            "Offer is already owned by this address"
+        );
+        require!(
+            !self.is_allowed_accepter(offer_id, &offer, &new_creator),
+            "New creator can not be a wanted address"
+        );
+
+        let arbitration_mapper = self.offer_arbitration(offer_id);
+
+        require!(
+            arbitration_mapper.is_empty() || arbitration_mapper.get().arbiter != new_creator,
+            "Arbiter must be a third party"
+        );
+
+        require!(
+            self.offer_approval_policy(offer_id).is_empty(),
+            "Multisig offers can not be transferred"
+        );
+
+        self.require_open_offers_limits(self.created_offers(&new_creator).len(), 0);
+
+        self.created_offers(&caller).swap_remove(&offer_id);
+        self.created_offers(&new_creator).insert(offer_id);
+
+        // The delivery and referrer were chosen by the previous owner, so the proceeds would not
+        // reach the new one.
+        self.offer_delivery(offer_id).clear();
+        self.offer_referrer(offer_id).clear();
+
+        offer.creator = new_creator;
+
+        self.offers(offer_id).set(offer);
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn accept(&self, offer_id: u64, options: MultiValueEncoded<AcceptOption<Self::Api>>) {
+        let offer = self.require_live_offer(offer_id);
+
+        let caller = self.blockchain().get_caller();
+
+        let commitment_mapper = self.offer_counterparty_commitment(offer_id);
+
+        require!(
+            !commitment_mapper.is_empty() || self.is_allowed_accepter(offer_id, &offer, &caller),
+            "Can not accept this offer"
+        );
+
+        require!(
+            self.offer_hashlock(offer_id).is_empty(),
+            "Hashlocked offers can only be claimed with the preimage"
+        );
+
+        self.require_approved(offer_id, ApprovalAction::Accept);
+
+        let payment = self.call_value().single_esdt();
+
+        require!(
+            self.matches_wanted_nft(offer_id, &offer, &payment),
+            "NFT does not match"
+        );
+
+        let mut accepter_delivery = None;
+        let mut accepter_referrer = None;
+        let mut salt = None;
+
+        for option in options {
+            match option {
+                AcceptOption::Delivery(delivery) => {
+                    self.require_valid_delivery(&delivery);
+                    accepter_delivery = Some(delivery);
+                }
+                AcceptOption::Referrer(referrer) => {
+                    require!(referrer != caller, "Can not refer yourself");
+                    accepter_referrer = Some(referrer);
+                }
+                AcceptOption::Salt(option_salt) => {
+                    salt = Some(option_salt);
+                }
+            }
+        }
+
+        if !commitment_mapper.is_empty() {
+            require!(
+                salt.is_some_and(|salt| {
+                    self.counterparty_commitment(&caller, &salt) == commitment_mapper.get()
+                }),
+                "Counterparty commitment does not match"
+            );
+        }
+
+        if !self.offer_arbitration(offer_id).is_empty() {
+            self.require_not_awaiting_arbitration(offer_id);
+
+            self.pending_acceptance(offer_id).set(PendingAcceptance {
+                accepter: caller,
+                payment,
+                delivery: accepter_delivery,
+                referrer: accepter_referrer,
+                deadline: self.blockchain().get_block_timestamp() + ARBITRATION_TIMEOUT_SECONDS,
+            });
+
+            self.set_offer_status(offer_id, OfferStatus::Disputed);
+
+            return;
+        }
+
+        self.complete_swap(
+            offer_id,
+            offer,
+            &caller,
+            payment,
+            accepter_delivery,
+            accepter_referrer,
+        );
+    }
+
+    #[endpoint(claimWithPreimage)]
+    fn claim_with_preimage(&self, offer_id: u64, preimage: ManagedBuffer) {
+        let offer = self.require_live_offer(offer_id);
+
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            self.is_allowed_accepter(offer_id, &offer, &caller),
+            "Only the wanted address can claim it"
+        );
+
+        let hashlock_mapper = self.offer_hashlock(offer_id);
+
+        require!(!hashlock_mapper.is_empty(), "Offer is not hashlocked");
+
+        self.require_approved(offer_id, ApprovalAction::Accept);
+
+        let hashlock = hashlock_mapper.get();
+
+        require!(
+            self.blockchain().get_block_timestamp() < hashlock.deadline,
+            "Hashlock has expired"
+        );
+        require!(
+            self.crypto().sha256(&preimage) == hashlock.hash,
+            "Invalid preimage"
+        );
+
+        let creator_referrer = self.offer_referrer(offer_id);
+        let creator_referrer = if creator_referrer.is_empty() {
+            None
+        } else {
+            Some(creator_referrer.get())
+        };
+
+        self.charge_swap_fee(offer_id, &[creator_referrer]);
+
+        self.remove_offer(offer_id, &offer, OfferStatus::Accepted);
+
+        self.offers_accepted_count(&caller)
+            .update(|count| *count += 1);
+
+        self.hashlock_claimed_event(offer_id, &preimage);
+
+        self.send_payment(
+            &caller,
+            EsdtTokenPayment::new(offer.nft, offer.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    #[endpoint(approveOfferAction)]
+    fn approve_offer_action(&self, offer_id: u64, action: ApprovalAction) {
+        self.require_live_offer(offer_id);
+
+        let policy_mapper = self.offer_approval_policy(offer_id);
+
+        require!(
+            !policy_mapper.is_empty(),
+            "Offer does not require approvals"
+        );
+
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            policy_mapper
+                .get()
+                .approvers
+                .iter()
+                .any(|approver| *approver == caller),
+            "Only an approver can sign off"
+        );
+        require!(
+            self.offer_approvals(offer_id, action).insert(caller),
+            "Action is already approved"
+        );
+    }
+
+    #[endpoint(approveArbitration)]
+    fn approve_arbitration(&self, offer_id: u64) {
+        let (offer, pending) = self.settle_arbitration(offer_id);
+
+        self.complete_swap(
+            offer_id,
+            offer,
+            &pending.accepter,
+            pending.payment,
+            pending.delivery,
+            pending.referrer,
+        );
+    }
+
+    #[endpoint(rejectArbitration)]
+    fn reject_arbitration(&self, offer_id: u64) {
+        let (offer, pending) = self.settle_arbitration(offer_id);
+
+        self.refund_disputed_offer(offer_id, offer, pending);
+    }
+
+    /// Lets either party unwind a dispute the arbiter never settled. The arbiter fee goes back to
+    /// the creator's bond.
+    #[endpoint(expireArbitration)]
+    fn expire_arbitration(&self, offer_id: u64) {
+        let offer = self.require_live_offer(offer_id);
+
+        let pending_mapper = self.pending_acceptance(offer_id);
+
+        require!(
+            !pending_mapper.is_empty(),
+            "Offer is not awaiting arbitration"
+        );
+
+        let pending = pending_mapper.get();
+
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            caller == offer.creator || caller == pending.accepter,
+            "Only the creator or the accepter can expire arbitration"
+        );
+        require!(
+            self.blockchain().get_block_timestamp() >= pending.deadline,
+            "Arbitration deadline has not passed"
+        );
+
+        self.refund_disputed_offer(offer_id, offer, pending);
+    }
+
+    #[endpoint]
+    fn decline(&self, offer_id: u64) {
+        let offer = self.require_live_offer(offer_id);
+
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            self.is_allowed_accepter(offer_id, &offer, &caller),
+            "Only the wanted address can decline it"
+        );
+
+        self.require_not_awaiting_arbitration(offer_id);
+
+        self.offers_declined_count(&caller)
+            .update(|count| *count += 1);
+
+        let mut accepters_mapper = self.offer_accepters(offer_id);
+
+        if accepters_mapper.swap_remove(&caller) {
+            self.wanted_offers(&caller).swap_remove(&offer_id);
+
+            return;
+        }
+
+        // The offer stays open while another accepter is allowed, so one of them takes the
+        // wanted address' place.
+        if !accepters_mapper.is_empty() {
+            let next_wanted_address = accepters_mapper.get_by_index(1);
+
+            accepters_mapper.swap_remove(&next_wanted_address);
+            self.wanted_offers(&caller).swap_remove(&offer_id);
+
+            self.offers(offer_id)
+                .update(|offer| offer.wanted_address = next_wanted_address);
+
+            return;
+        }
+
+        self.remove_offer(offer_id, &offer, OfferStatus::Declined);
+
+        self.send_payment(
+            &offer.creator,
+            EsdtTokenPayment::new(offer.nft, offer.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    /// Returns the escrowed NFTs of offers. Wishlist entries, bids, loans and rentals have their
+    /// own emergency endpoints.
+    #[only_owner]
+    #[endpoint(emergencyWithdraw)]
+    fn emergency_withdraw(&self, batch_size: u32) -> bool {
+        self.require_offer_ids_migrated();
+        self.require_emergency_unlocked();
+
+        let last_offer_id = self.last_offer_id().get();
+        let mut offer_id = self.emergency_cursor().get();
+        let mut scanned = 0;
+        let mut finished = offer_id > last_offer_id;
+
+        while !finished && scanned < batch_size {
+            if self.is_live_offer(offer_id) {
+                let offer = self.offers(offer_id).get();
+
+                let pending_mapper = self.pending_acceptance(offer_id);
+
+                if !pending_mapper.is_empty() {
+                    let pending = pending_mapper.get();
+
+                    self.send_payment(&pending.accepter, pending.payment);
+                }
+
+                self.remove_offer(offer_id, &offer, OfferStatus::Cancelled);
+
+                self.emergency_return_event(offer_id, &offer.creator);
+
+                self.send_payment(
+                    &offer.creator,
+                    EsdtTokenPayment::new(offer.nft, offer.nonce, BigUint::from(1u64)),
+                );
+            }
+
+            finished = offer_id == last_offer_id;
+            offer_id = offer_id.saturating_add(1);
+            scanned += 1;
+        }
+
+        self.emergency_cursor().set(offer_id);
+
+        finished
+    }
+
+    #[only_owner]
+    #[endpoint(migrateOfferIds)]
+    fn migrate_offer_ids(&self, batch_size: u32) -> bool {
+        let cursor_mapper = self.offer_id_migration_cursor();
+
+        require!(
+            !cursor_mapper.is_empty(),
+            "No offer ID migration in progress"
+        );
+
+        let last_offer_id = self.last_offer_id().get();
+        let mut offer_id = cursor_mapper.get();
+        let mut budget = batch_size;
+
+        while offer_id <= last_offer_id && budget > 0 {
+            if !self.migrate_offer_id(offer_id, &mut budget) {
+                break;
+            }
+
+            offer_id += 1;
+            budget = budget.saturating_sub(1);
+        }
+
+        if offer_id > last_offer_id {
+            self.finish_offer_id_migration();
+
+            return true;
+        }
+
+        cursor_mapper.set(offer_id);
+
+        false
+    }
+
+    #[view(getCreatedOffers)]
+    fn get_created_offers(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<u64, Offer<Self::Api>>> {
+        self.require_offer_ids_migrated();
+
+        let mut result = MultiValueEncoded::new();
+
+        for offer_id in self.created_offers(&address).iter() {
+            result.push(self.get_offer_result(offer_id));
+        }
+
+        result
+    }
+
+    #[view(getWantedOffers)]
+    fn get_wanted_offers(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<u64, Offer<Self::Api>>> {
+        self.require_offer_ids_migrated();
+
+        let mut result = MultiValueEncoded::new();
+
+        for offer_id in self.wanted_offers(&address).iter() {
+            result.push(self.get_offer_result(offer_id));
+        }
+
+        result
+    }
+
+    #[view(getOffer)]
+    fn get_offer(&self, offer_id: u64) -> OptionalValue<Offer<Self::Api>> {
+        self.find_offer(offer_id).into()
+    }
+
+    #[view(getOffers)]
+    fn get_offers(
+        &self,
+        offer_ids: MultiValueEncoded<u64>,
+    ) -> MultiValueEncoded<MultiValue2<u64, Option<Offer<Self::Api>>>> {
+        let mut result = MultiValueEncoded::new();
+
+        for offer_id in offer_ids {
+            result.push(MultiValue2::from((offer_id, self.find_offer(offer_id))));
+        }
+
+        result
+    }
+
+    #[view(offerStatus)]
+    fn offer_status(&self, offer_id: u64) -> OfferStatus {
+        let offers_mapper = self.offers(offer_id);
+
+        require!(!offers_mapper.is_empty(), "Offer does not exist");
+
+        offers_mapper.get().status
+    }
+
+    /// Sums what the contract owes and reports every token it holds less of. Live offers are
+    /// covered for the given ID range, with their locked deposits, fees and arbiter fees. The
+    /// listed addresses are covered for their bonds, referral rewards, claimable payouts, custody,
+    /// wishlist entries, bids, loans, lender repayments and rentals. Collected fees are always
+    /// covered.
+    #[view(getSolvencyReport)]
+    fn get_solvency_report(
+        &self,
+        from_offer_id: u64,
+        to_offer_id: u64,
+        addresses: MultiValueEncoded<ManagedAddress>,
+    ) -> MultiValueEncoded<Discrepancy<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let mut owed = ManagedVec::new();
+        let mut owed_egld = self.collected_fees().get();
+
+        let to_offer_id = core::cmp::min(to_offer_id, self.last_offer_id().get());
+
+        for offer_id in from_offer_id..=to_offer_id {
+            if !self.is_live_offer(offer_id) {
+                continue;
+            }
+
+            let offer = self.offers(offer_id).get();
+
+            self.add_owed_payment(
+                &mut owed,
+                EsdtTokenPayment::new(offer.nft.clone(), offer.nonce, BigUint::from(1u64)),
+            );
+
+            let pending_mapper = self.pending_acceptance(offer_id);
+
+            if !pending_mapper.is_empty() {
+                self.add_owed_payment(&mut owed, pending_mapper.get().payment);
+            }
+
+            owed_egld += self.locked_deposit(offer_id).get();
+            owed_egld += self.locked_fee(offer_id).get();
+
+            let arbitration_mapper = self.offer_arbitration(offer_id);
+
+            if !arbitration_mapper.is_empty() {
+                owed_egld += arbitration_mapper.get().fee;
+            }
+
+            if !self.created_offers(&offer.creator).contains(&offer_id) {
+                result.push(Discrepancy::MissingCreatedIndex(offer_id));
+            }
+
+            if self.offer_counterparty_commitment(offer_id).is_empty()
+                && !self
+                    .wanted_offers(&offer.wanted_address)
+                    .contains(&offer_id)
+            {
+                result.push(Discrepancy::MissingWantedIndex(offer_id));
+            }
+
+            for accepter in self.offer_accepters(offer_id).iter() {
+                if !self.wanted_offers(&accepter).contains(&offer_id) {
+                    result.push(Discrepancy::MissingWantedIndex(offer_id));
+                }
+            }
+        }
+
+        for address in addresses {
+            for offer_id in self.created_offers(&address).iter() {
+                if !self.is_live_offer(offer_id) {
+                    result.push(Discrepancy::DanglingCreatedIndex(address.clone(), offer_id));
+                }
+            }
+
+            for offer_id in self.wanted_offers(&address).iter() {
+                if !self.is_live_offer(offer_id) {
+                    result.push(Discrepancy::DanglingWantedIndex(address.clone(), offer_id));
+                }
+            }
+
+            owed_egld += self.bond_balance(&address).get();
+            owed_egld += self.referral_rewards(&address).get();
+
+            for payment in self.claimable_payouts(&address).iter() {
+                self.add_owed_payment(&mut owed, payment);
+            }
+
+            for payment in self.custody(&address).iter() {
+                self.add_owed_payment(&mut owed, payment);
+            }
+
+            for entry_id in self.wishlist(&address).iter() {
+                let entry = self.wishlist_entries(entry_id).get();
+
+                self.add_owed_payment(
+                    &mut owed,
+                    EsdtTokenPayment::new(entry.nft, entry.nonce, BigUint::from(1u64)),
+                );
+            }
+
+            for bid_id in self.bidder_bids(&address).iter() {
+                let bid = self.bids(bid_id).get();
+
+                self.add_owed_payment(
+                    &mut owed,
+                    EsdtTokenPayment::new(bid.nft, bid.nonce, BigUint::from(1u64)),
+                );
+            }
+
+            for loan_id in self.borrower_loans(&address).iter() {
+                let loan = self.loans(loan_id).get();
+
+                self.add_owed_payment(
+                    &mut owed,
+                    EsdtTokenPayment::new(loan.nft, loan.nonce, BigUint::from(1u64)),
+                );
+            }
+
+            for token in self.lender_repayment_tokens(&address).iter() {
+                let amount = self.lender_repayments(&address, &token).get();
+
+                self.add_owed_amount(&mut owed, &mut owed_egld, token, amount);
+            }
+
+            for rental_id in self.owner_rentals(&address).iter() {
+                let rental = self.rentals(rental_id).get();
+
+                if rental.renter.is_some() {
+                    self.add_owed_amount(
+                        &mut owed,
+                        &mut owed_egld,
+                        rental.token,
+                        rental.collateral,
+                    );
+                } else {
+                    self.add_owed_payment(
+                        &mut owed,
+                        EsdtTokenPayment::new(rental.nft, rental.nonce, BigUint::from(1u64)),
+                    );
+                }
+            }
+        }
+
+        let sc_address = self.blockchain().get_sc_address();
+
+        for payment in owed.iter() {
+            let balance = self.blockchain().get_esdt_balance(
+                &sc_address,
+                &payment.token_identifier,
+                payment.token_nonce,
+            );
+
+            if balance < payment.amount {
+                result.push(Discrepancy::Shortfall(
+                    payment.token_identifier.clone(),
+                    payment.token_nonce,
+                    &payment.amount - &balance,
+                ));
+            }
+        }
+
+        let egld_balance = self.blockchain().get_balance(&sc_address);
+
+        if egld_balance < owed_egld {
+            result.push(Discrepancy::EgldShortfall(owed_egld - egld_balance));
+        }
+
+        result
+    }
+
+    /// Obligations on the same token and nonce are summed, so that several of them can not be
+    /// backed by a single balance.
+    fn add_owed_payment(&self, owed: &mut ManagedVec<EsdtTokenPayment>, payment: EsdtTokenPayment) {
+        let position = owed.iter().position(|existing| {
+            existing.token_identifier == payment.token_identifier
+                && existing.token_nonce == payment.token_nonce
+        });
+
+        match position {
+            Some(index) => {
+                let mut existing = owed.get(index).clone();
+                existing.amount += payment.amount;
 
-    #[payable("*")]
-    #[endpoint]
-    fn escrow(
+                let _ = owed.set(index, &existing);
+            }
+            None => owed.push(payment),
+        }
+    }
+
+    fn add_owed_amount(
+        &self,
+        owed: &mut ManagedVec<EsdtTokenPayment>,
+        owed_egld: &mut BigUint,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        if amount == 0 {
+            return;
+        }
+
+        if token.is_egld() {
+            *owed_egld += amount;
+        } else {
+            self.add_owed_payment(owed, EsdtTokenPayment::new(token.unwrap_esdt(), 0, amount));
+        }
+    }
+
+    fn create_offer(
         &self,
+        creator: ManagedAddress,
         wanted_nft: TokenIdentifier,
         wanted_nonce: u64,
         wanted_address: ManagedAddress,
-    ) -> u32 {
+        options: MultiValueEncoded<EscrowOption<Self::Api>>,
+    ) -> u64 {
+        self.require_offer_ids_migrated();
+        self.require_no_emergency();
+
         let payment = self.call_value().single_esdt();
 
         require!(
             payment.token_nonce > 0 && payment.amount == 1,
             "ESDT is not an NFT"
         );
-        require!(wanted_nonce > 0, "Wanted ESDT is not an NFT");
 
-        let creator = self.blockchain().get_caller();
+        let mut delivery = None;
+        let mut wanted_attributes = None;
+        let mut memo = ManagedBuffer::new();
+        let mut referrer = None;
+        let mut arbitration = None;
+        let mut hashlock = None;
+        let mut commitment = None;
+        let mut accepters = ManagedVec::<Self::Api, ManagedAddress>::new();
+        let mut approval_policy = None;
+
+        for option in options {
+            match option {
+                EscrowOption::Delivery(option_delivery) => {
+                    self.require_valid_delivery(&option_delivery);
+                    delivery = Some(option_delivery);
+                }
+                EscrowOption::WantedAttributes(attributes) => {
+                    require!(
+                        !attributes.is_empty() && attributes.len() <= MAX_WANTED_ATTRIBUTES_LEN,
+                        "Invalid wanted attributes"
+                    );
+                    wanted_attributes = Some(attributes);
+                }
+                EscrowOption::Memo(option_memo) => {
+                    require!(option_memo.len() <= MAX_MEMO_LEN, "Memo is too long");
+                    memo = option_memo;
+                }
+                EscrowOption::Referrer(option_referrer) => {
+                    require!(option_referrer != creator, "Can not refer yourself");
+                    referrer = Some(option_referrer);
+                }
+                EscrowOption::Arbitration(option_arbitration) => {
+                    require!(
+                        option_arbitration.arbiter != creator
+                            && option_arbitration.arbiter != wanted_address,
+                        "Arbiter must be a third party"
+                    );
+                    arbitration = Some(option_arbitration);
+                }
+                EscrowOption::Hashlock(option_hashlock) => {
+                    require!(
+                        option_hashlock.deadline > self.blockchain().get_block_timestamp(),
+                        "Hashlock deadline is in the past"
+                    );
+                    hashlock = Some(option_hashlock);
+                }
+                EscrowOption::CounterpartyCommitment(option_commitment) => {
+                    commitment = Some(option_commitment);
+                }
+                EscrowOption::Accepters(option_accepters) => {
+                    accepters = option_accepters;
+                }
+                EscrowOption::Approvers(option_policy) => {
+                    self.require_valid_approval_policy(&option_policy);
+                    approval_policy = Some(option_policy);
+                }
+            }
+        }
+
+        require!(
+            accepters.len() <= MAX_EXTRA_ACCEPTERS,
+            "Too many allowed accepters"
+        );
+        require!(
+            accepters.is_empty() || commitment.is_none(),
+            "Private offers can not list accepters"
+        );
+
+        for (index, accepter) in accepters.iter().enumerate() {
+            require!(
+                *accepter != creator && *accepter != wanted_address,
+                "Invalid allowed accepter"
+            );
+            require!(
+                !accepters
+                    .iter()
+                    .skip(index + 1)
+                    .any(|other| *other == *accepter),
+                "Duplicate allowed accepter"
+            );
+            require!(
+                arbitration
+                    .as_ref()
+                    .is_none_or(|arbitration| arbitration.arbiter != *accepter),
+                "Arbiter must be a third party"
+            );
+
+            self.require_open_offers_limits(0, self.wanted_offers(&accepter).len());
+        }
+
+        require!(
+            commitment.is_none() || wanted_address.is_zero(),
+            "Wanted address must be empty for private offers"
+        );
+        require!(
+            commitment.is_none() || hashlock.is_none(),
+            "Hashlocked offers can not be private"
+        );
+
+        require!(
+            arbitration.is_none() || hashlock.is_none(),
+            "Hashlocked offers can not be arbitrated"
+        );
+
+        require!(
+            wanted_nonce > 0 || wanted_attributes.is_some(),
+            "Wanted ESDT is not an NFT"
+        );
 
         require!(
             creator != wanted_address,
             "Wanted address should not be the same as the caller"
         );
 
+        self.require_open_offers_limits(
+            self.created_offers(&creator).len(),
+            self.wanted_offers(&wanted_address).len(),
+        );
+
         let offer_id = self.last_offer_id().update(|v| {
-            *v += 1;
+            *v = match v.checked_add(1) {
+                Some(next) => next,
+                None => sc_panic!("Offer ID overflow"),
+            };
 
             *v
         });
 
+        let settles_immediately =
+            arbitration.is_none() && hashlock.is_none() && approval_policy.is_none();
+
+        self.lock_offer_deposit(&creator, offer_id);
+        self.lock_swap_fee(&creator, offer_id);
+
+        if let Some(arbitration) = &arbitration {
+            self.lock_arbiter_fee(&creator, &arbitration.fee);
+        }
+
+        self.offers_created_count(&creator)
+            .update(|count| *count += 1);
+
         self.created_offers(&creator).insert(offer_id);
-        self.wanted_offers(&wanted_address).insert(offer_id);
+        if commitment.is_none() {
+            self.wanted_offers(&wanted_address).insert(offer_id);
+        }
+
+        for accepter in accepters.iter() {
+            self.wanted_offers(&accepter).insert(offer_id);
+            self.offer_accepters(offer_id)
+                .insert(accepter.clone_value());
+        }
 
         let offer = Offer {
             creator,
@@ -58,112 +1063,539 @@ pub trait NftEscrowContract {
             wanted_nft,
             wanted_nonce,
             wanted_address,
+            memo,
+            status: OfferStatus::Open,
         };
 
         self.offers(offer_id).set(offer);
 
+        if let Some(delivery) = delivery {
+            self.offer_delivery(offer_id).set(delivery);
+        }
+
+        if let Some(attributes) = wanted_attributes {
+            self.offer_wanted_attributes(offer_id).set(attributes);
+        }
+
+        if let Some(referrer) = referrer {
+            self.offer_referrer(offer_id).set(referrer);
+        }
+
+        if let Some(arbitration) = arbitration {
+            self.offer_arbitration(offer_id).set(arbitration);
+        }
+
+        if let Some(hashlock) = hashlock {
+            self.offer_hashlock(offer_id).set(hashlock);
+        }
+
+        if let Some(commitment) = commitment {
+            self.offer_counterparty_commitment(offer_id).set(commitment);
+        }
+
+        if let Some(policy) = approval_policy {
+            self.offer_approval_policy(offer_id).set(policy);
+        }
+
+        if settles_immediately {
+            self.match_wishlist(offer_id);
+        }
+
         offer_id
     }
 
-    #[endpoint]
-    fn cancel(&self, offer_id: u32) {
-        let offers_mapper = self.offers(offer_id);
+    fn match_wishlist(&self, offer_id: u64) {
+        let offer = self.offers(offer_id).get();
 
-        require!(!offers_mapper.is_empty(), "Offer does not exist");
+        let matching_entry = self
+            .wishlist(&offer.wanted_address)
+            .iter()
+            .map(|entry_id| (entry_id, self.wishlist_entries(entry_id).get()))
+            .find(|(_, entry)| {
+                entry.wanted_nft == offer.nft
+                    && entry.wanted_nonce == offer.nonce
+                    && self.matches_wanted_nft(
+                        offer_id,
+                        &offer,
+                        &EsdtTokenPayment::new(entry.nft.clone(), entry.nonce, BigUint::from(1u64)),
+                    )
+            });
 
-        let caller = self.blockchain().get_caller();
+        if let Some((entry_id, entry)) = matching_entry {
+            self.take_wishlist_entry(entry_id, &entry);
 
-        let offer = offers_mapper.get();
+            self.complete_swap(
+                offer_id,
+                offer,
+                &entry.owner,
+                EsdtTokenPayment::new(entry.nft, entry.nonce, BigUint::from(1u64)),
+                None,
+                None,
+            );
+        }
+    }
+
+    fn cancel_offers(&self, caller: &ManagedAddress, offer_ids: ManagedVec<u64>) {
+        require!(!offer_ids.is_empty(), "No offers to cancel");
+
+        let mut creators = ManagedVec::<Self::Api, ManagedAddress>::new();
+        let mut payments = ManagedVec::<Self::Api, EsdtTokenPayment>::new();
+
+        for offer_id in offer_ids.iter() {
+            let offer = self.cancel_offer(caller, offer_id);
+
+            creators.push(offer.creator);
+            payments.push(EsdtTokenPayment::new(
+                offer.nft,
+                offer.nonce,
+                BigUint::from(1u64),
+            ));
+        }
+
+        // An operator can cancel offers of several creators, each of which gets a single transfer.
+        for (index, creator) in creators.iter().enumerate() {
+            if creators.iter().take(index).any(|other| *other == *creator) {
+                continue;
+            }
+
+            let creator_payments = creators
+                .iter()
+                .zip(payments.iter())
+                .skip(index)
+                .filter(|(other, _)| **other == *creator)
+                .map(|(_, payment)| payment.clone())
+                .collect();
+
+            self.send_payments(&creator, creator_payments);
+        }
+    }
+
+    fn cancel_offer(&self, caller: &ManagedAddress, offer_id: u64) -> Offer<Self::Api> {
+        let offer = self.require_live_offer(offer_id);
 
         require!(
-            offer.creator == caller,
-            "Only the offer creator can cancel it"
+            self.can_act_for(&offer.creator, caller),
+            "Only the offer creator or an approved operator can cancel it"
         );
 
-        self.created_offers(&caller).swap_remove(&offer_id);
+        self.require_not_awaiting_arbitration(offer_id);
+        self.require_hashlock_expired(offer_id);
+        self.require_approved(offer_id, ApprovalAction::Cancel);
+
+        self.remove_offer(offer_id, &offer, self.cancellation_status(offer_id));
+
+        self.offers_cancelled_count(&offer.creator)
+            .update(|count| *count += 1);
+
+        offer
+    }
+
+    fn remove_offer(&self, offer_id: u64, offer: &Offer<Self::Api>, status: OfferStatus) {
+        self.set_offer_status(offer_id, status);
+
+        self.created_offers(&offer.creator).swap_remove(&offer_id);
         self.wanted_offers(&offer.wanted_address)
             .swap_remove(&offer_id);
 
-        self.offers(offer_id).clear();
+        for accepter in self.offer_accepters(offer_id).iter() {
+            self.wanted_offers(&accepter).swap_remove(&offer_id);
+        }
+
+        self.offer_accepters(offer_id).clear();
 
-        self.tx()
-            .to(&offer.creator)
-            .payment(EsdtTokenPayment::new(
-                offer.nft,
-                offer.nonce,
-                BigUint::from(1u64),
-            ))
-            .transfer();
+        self.offer_delivery(offer_id).clear();
+        self.offer_wanted_attributes(offer_id).clear();
+        self.offer_referrer(offer_id).clear();
+        self.pending_acceptance(offer_id).clear();
+        self.offer_hashlock(offer_id).clear();
+        self.offer_counterparty_commitment(offer_id).clear();
+        self.offer_approval_policy(offer_id).clear();
+        self.offer_approvals(offer_id, ApprovalAction::Accept)
+            .clear();
+        self.offer_approvals(offer_id, ApprovalAction::Cancel)
+            .clear();
+
+        self.release_offer_deposit(&offer.creator, offer_id);
+        self.release_swap_fee(&offer.creator, offer_id);
+
+        let arbitration_mapper = self.offer_arbitration(offer_id);
+
+        if !arbitration_mapper.is_empty() {
+            let fee = arbitration_mapper.take().fee;
+
+            self.bond_balance(&offer.creator)
+                .update(|balance| *balance += fee);
+        }
     }
 
-    #[payable("*")]
-    #[endpoint]
-    fn accept(&self, offer_id: u32) {
+    /// Legacy offer IDs never exceeded `u32::MAX`, so the cursor always fits the old key width.
+    /// The offer is only moved once both of its indexes are, and stays behind when the budget runs
+    /// out first.
+    fn migrate_offer_id(&self, offer_id: u64, budget: &mut u32) -> bool {
+        let legacy_mapper = self.legacy_offers(offer_id as u32);
+
+        if legacy_mapper.is_empty() {
+            return true;
+        }
+
+        let legacy_offer = legacy_mapper.get();
+
+        if !self.migrate_offer_index(
+            offer_id_migration::OfferIndex::Created,
+            &legacy_offer.creator,
+            budget,
+        ) || !self.migrate_offer_index(
+            offer_id_migration::OfferIndex::Wanted,
+            &legacy_offer.wanted_address,
+            budget,
+        ) {
+            return false;
+        }
+
+        legacy_mapper.clear();
+
+        self.offers(offer_id).set(legacy_offer.into_offer());
+
+        true
+    }
+
+    fn find_offer(&self, offer_id: u64) -> Option<Offer<Self::Api>> {
+        let offers_mapper = self.offers(offer_id);
+
+        if offers_mapper.is_empty() {
+            None
+        } else {
+            Some(offers_mapper.get())
+        }
+    }
+
+    fn require_live_offer(&self, offer_id: u64) -> Offer<Self::Api> {
+        self.require_offer_ids_migrated();
+
         let offers_mapper = self.offers(offer_id);
 
         require!(!offers_mapper.is_empty(), "Offer does not exist");
 
         let offer = offers_mapper.get();
 
-        let caller = self.blockchain().get_caller();
+        require!(offer.status.is_live(), "Offer is closed");
 
-        require!(offer.wanted_address == caller, "Can not accept this offer");
+        offer
+    }
 
-        let payment = self.call_value().single_esdt();
+    fn is_live_offer(&self, offer_id: u64) -> bool {
+        let offers_mapper = self.offers(offer_id);
+
+        !offers_mapper.is_empty() && offers_mapper.get().status.is_live()
+    }
+
+    fn set_offer_status(&self, offer_id: u64, status: OfferStatus) {
+        self.offers(offer_id).update(|offer| {
+            require!(
+                offer.status.can_transition_to(status),
+                "Invalid offer status transition"
+            );
+
+            offer.status = status;
+        });
+    }
+
+    fn cancellation_status(&self, offer_id: u64) -> OfferStatus {
+        if self.offer_hashlock(offer_id).is_empty() {
+            OfferStatus::Cancelled
+        } else {
+            OfferStatus::Expired
+        }
+    }
+
+    fn complete_swap(
+        &self,
+        offer_id: u64,
+        offer: Offer<Self::Api>,
+        accepter: &ManagedAddress,
+        payment: EsdtTokenPayment,
+        accepter_delivery: Option<Delivery<Self::Api>>,
+        accepter_referrer: Option<ManagedAddress>,
+    ) {
+        let creator_delivery = self.offer_delivery(offer_id);
+        let creator_delivery = if creator_delivery.is_empty() {
+            None
+        } else {
+            Some(creator_delivery.get())
+        };
+
+        let creator_referrer = self.offer_referrer(offer_id);
+        let creator_referrer = if creator_referrer.is_empty() {
+            None
+        } else {
+            Some(creator_referrer.get())
+        };
+
+        self.charge_swap_fee(offer_id, &[creator_referrer, accepter_referrer]);
+
+        self.remove_offer(offer_id, &offer, OfferStatus::Accepted);
+
+        self.offers_accepted_count(accepter)
+            .update(|count| *count += 1);
+
+        self.deliver(&offer.creator, payment, creator_delivery);
+        self.deliver(
+            accepter,
+            EsdtTokenPayment::new(offer.nft, offer.nonce, BigUint::from(1u64)),
+            accepter_delivery,
+        );
+    }
+
+    fn lock_arbiter_fee(&self, creator: &ManagedAddress, fee: &BigUint) {
+        if *fee == 0 {
+            return;
+        }
+
+        let bond_mapper = self.bond_balance(creator);
 
         require!(
-            payment.token_identifier == offer.wanted_nft
-                && payment.token_nonce == offer.wanted_nonce
-                && payment.amount == 1,
-            "NFT does not match"
+            bond_mapper.get() >= *fee,
+            "Insufficient bond for the arbiter fee"
         );
 
-        self.created_offers(&offer.creator).swap_remove(&offer_id);
-        self.wanted_offers(&offer.wanted_address)
-            .swap_remove(&offer_id);
+        bond_mapper.update(|balance| *balance -= fee);
+    }
+
+    fn settle_arbitration(
+        &self,
+        offer_id: u64,
+    ) -> (Offer<Self::Api>, PendingAcceptance<Self::Api>) {
+        let offer = self.require_live_offer(offer_id);
 
-        self.offers(offer_id).clear();
+        let arbitration_mapper = self.offer_arbitration(offer_id);
 
-        self.tx().to(&offer.creator).payment(payment).transfer();
-        self.tx()
-            .to(&offer.wanted_address)
-            .payment(EsdtTokenPayment::new(
-                offer.nft,
-                offer.nonce,
-                BigUint::from(1u64),
-            ))
-            .transfer();
+        require!(
+            !arbitration_mapper.is_empty()
+                && arbitration_mapper.get().arbiter == self.blockchain().get_caller(),
+            "Only the arbiter can settle this offer"
+        );
+
+        let pending_mapper = self.pending_acceptance(offer_id);
+
+        require!(
+            !pending_mapper.is_empty(),
+            "Offer is not awaiting arbitration"
+        );
+
+        let arbitration = arbitration_mapper.take();
+
+        if arbitration.fee > 0 {
+            self.tx()
+                .to(&arbitration.arbiter)
+                .egld(arbitration.fee)
+                .transfer();
+        }
+
+        (offer, pending_mapper.take())
     }
 
-    #[view(getCreatedOffers)]
-    fn get_created_offers(
+    fn refund_disputed_offer(
         &self,
-        address: ManagedAddress,
-    ) -> MultiValueEncoded<MultiValue2<u32, Offer<Self::Api>>> {
-        let mut result = MultiValueEncoded::new();
+        offer_id: u64,
+        offer: Offer<Self::Api>,
+        pending: PendingAcceptance<Self::Api>,
+    ) {
+        self.remove_offer(offer_id, &offer, OfferStatus::Cancelled);
 
-        for offer_id in self.created_offers(&address).iter() {
-            result.push(self.get_offer_result(offer_id));
+        self.send_payment(
+            &offer.creator,
+            EsdtTokenPayment::new(offer.nft, offer.nonce, BigUint::from(1u64)),
+        );
+        self.send_payment(&pending.accepter, pending.payment);
+    }
+
+    fn is_allowed_accepter(
+        &self,
+        offer_id: u64,
+        offer: &Offer<Self::Api>,
+        address: &ManagedAddress,
+    ) -> bool {
+        offer.wanted_address == *address || self.offer_accepters(offer_id).contains(address)
+    }
+
+    fn counterparty_commitment(
+        &self,
+        counterparty: &ManagedAddress,
+        salt: &ManagedBuffer,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        let mut preimage = counterparty.as_managed_buffer().clone();
+        preimage.append(salt);
+
+        self.crypto().sha256(&preimage)
+    }
+
+    fn require_valid_approval_policy(&self, policy: &ApprovalPolicy<Self::Api>) {
+        require!(
+            policy.approvers.len() <= MAX_APPROVERS,
+            "Too many approvers"
+        );
+        require!(
+            policy.threshold > 0 && policy.threshold as usize <= policy.approvers.len(),
+            "Invalid approval threshold"
+        );
+
+        for (index, approver) in policy.approvers.iter().enumerate() {
+            require!(
+                !policy
+                    .approvers
+                    .iter()
+                    .skip(index + 1)
+                    .any(|other| *other == *approver),
+                "Duplicate approver"
+            );
         }
+    }
 
-        result
+    fn require_approved(&self, offer_id: u64, action: ApprovalAction) {
+        require!(self.is_approved(offer_id, action), "Not enough approvals");
     }
 
-    #[view(getWantedOffers)]
-    fn get_wanted_offers(
+    fn require_hashlock_expired(&self, offer_id: u64) {
+        require!(
+            self.is_hashlock_expired(offer_id),
+            "Hashlock has not expired"
+        );
+    }
+
+    fn require_not_awaiting_arbitration(&self, offer_id: u64) {
+        require!(
+            !self.is_awaiting_arbitration(offer_id),
+            "Offer is awaiting arbitration"
+        );
+    }
+
+    fn is_approved(&self, offer_id: u64, action: ApprovalAction) -> bool {
+        let policy_mapper = self.offer_approval_policy(offer_id);
+
+        policy_mapper.is_empty()
+            || self.offer_approvals(offer_id, action).len()
+                >= policy_mapper.get().threshold as usize
+    }
+
+    fn is_hashlock_expired(&self, offer_id: u64) -> bool {
+        let hashlock_mapper = self.offer_hashlock(offer_id);
+
+        hashlock_mapper.is_empty()
+            || self.blockchain().get_block_timestamp() >= hashlock_mapper.get().deadline
+    }
+
+    fn is_awaiting_arbitration(&self, offer_id: u64) -> bool {
+        !self.pending_acceptance(offer_id).is_empty()
+    }
+
+    /// Mirrors the checks of `cancel_offer` that can keep a live offer open, so `cancelAll` can
+    /// leave such offers in place instead of failing the whole batch.
+    fn is_cancellable(&self, offer_id: u64) -> bool {
+        !self.is_awaiting_arbitration(offer_id)
+            && self.is_hashlock_expired(offer_id)
+            && self.is_approved(offer_id, ApprovalAction::Cancel)
+    }
+
+    fn matches_wanted_nft(
         &self,
-        address: ManagedAddress,
-    ) -> MultiValueEncoded<MultiValue2<u32, Offer<Self::Api>>> {
-        let mut result = MultiValueEncoded::new();
+        offer_id: u64,
+        offer: &Offer<Self::Api>,
+        payment: &EsdtTokenPayment,
+    ) -> bool {
+        if payment.token_identifier != offer.wanted_nft || payment.amount != 1 {
+            return false;
+        }
 
-        for offer_id in self.wanted_offers(&address).iter() {
-            result.push(self.get_offer_result(offer_id));
+        let wanted_attributes_mapper = self.offer_wanted_attributes(offer_id);
+
+        if wanted_attributes_mapper.is_empty() {
+            return payment.token_nonce == offer.wanted_nonce;
         }
 
-        result
+        if payment.token_nonce == 0
+            || (offer.wanted_nonce > 0 && payment.token_nonce != offer.wanted_nonce)
+        {
+            return false;
+        }
+
+        let attributes = self
+            .blockchain()
+            .get_esdt_token_data(
+                &self.blockchain().get_sc_address(),
+                &payment.token_identifier,
+                payment.token_nonce,
+            )
+            .attributes;
+
+        self.contains(&attributes, &wanted_attributes_mapper.get())
+    }
+
+    fn contains(&self, haystack: &ManagedBuffer, needle: &ManagedBuffer) -> bool {
+        let needle_len = needle.len();
+
+        if needle_len > haystack.len() {
+            return false;
+        }
+
+        for start in 0..=haystack.len() - needle_len {
+            if let Some(slice) = haystack.copy_slice(start, needle_len) {
+                if slice == *needle {
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    fn require_valid_delivery(&self, delivery: &Delivery<Self::Api>) {
+        require!(
+            self.blockchain().is_smart_contract(&delivery.destination),
+            "Delivery destination is not a smart contract"
+        );
+        require!(!delivery.function.is_empty(), "Delivery function is empty");
+        require!(
+            delivery.gas_limit > 0,
+            "Delivery gas limit must be positive"
+        );
+    }
+
+    fn deliver(
+        &self,
+        to: &ManagedAddress,
+        payment: EsdtTokenPayment,
+        delivery: Option<Delivery<Self::Api>>,
+    ) {
+        match delivery {
+            Some(delivery) => self
+                .tx()
+                .to(&delivery.destination)
+                .gas(delivery.gas_limit)
+                .raw_call(delivery.function)
+                .arguments_raw(delivery.arguments.into())
+                .payment(payment.clone())
+                .callback(self.callbacks().delivery_callback(to, payment))
+                .gas_for_callback(DELIVERY_CALLBACK_GAS)
+                .register_promise(),
+            None => self.send_payment(to, payment),
+        }
     }
 
-    fn get_offer_result(&self, offer_id: u32) -> MultiValue2<u32, Offer<Self::Api>> {
+    /// A failed delivery returns the payment to the escrow, so it is credited to the recipient's
+    /// claimable payouts instead of being left without an owner.
+    #[promises_callback]
+    fn delivery_callback(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+        recipient: &ManagedAddress,
+        payment: EsdtTokenPayment,
+    ) {
+        if result.is_err() {
+            self.claimable_payouts(recipient).push(&payment);
+        }
+    }
+
+    fn get_offer_result(&self, offer_id: u64) -> MultiValue2<u64, Offer<Self::Api>> {
         let offer = self.offers(offer_id).get();
 
         MultiValue2::from((offer_id, offer))
@@ -171,16 +1603,62 @@ pub trait NftEscrowContract {
 
     #[view]
     #[storage_mapper("createdOffers")]
-    fn created_offers(&self, address: &ManagedAddress) -> UnorderedSetMapper<u32>;
+    fn created_offers(&self, address: &ManagedAddress) -> UnorderedSetMapper<u64>;
 
     #[view]
     #[storage_mapper("wantedOffers")]
-    fn wanted_offers(&self, address: &ManagedAddress) -> UnorderedSetMapper<u32>;
+    fn wanted_offers(&self, address: &ManagedAddress) -> UnorderedSetMapper<u64>;
 
     #[view]
     #[storage_mapper("offers")]
-    fn offers(&self, id: u32) -> SingleValueMapper<Offer<Self::Api>>;
+    fn offers(&self, id: u64) -> SingleValueMapper<Offer<Self::Api>>;
+
+    #[view(getOfferDelivery)]
+    #[storage_mapper("offerDelivery")]
+    fn offer_delivery(&self, offer_id: u64) -> SingleValueMapper<Delivery<Self::Api>>;
+
+    #[view(getOfferWantedAttributes)]
+    #[storage_mapper("offerWantedAttributes")]
+    fn offer_wanted_attributes(&self, offer_id: u64) -> SingleValueMapper<ManagedBuffer>;
+
+    #[view(getOfferArbitration)]
+    #[storage_mapper("offerArbitration")]
+    fn offer_arbitration(&self, offer_id: u64) -> SingleValueMapper<Arbitration<Self::Api>>;
+
+    #[view(getPendingAcceptance)]
+    #[storage_mapper("pendingAcceptance")]
+    fn pending_acceptance(&self, offer_id: u64) -> SingleValueMapper<PendingAcceptance<Self::Api>>;
+
+    #[view(getOfferHashlock)]
+    #[storage_mapper("offerHashlock")]
+    fn offer_hashlock(&self, offer_id: u64) -> SingleValueMapper<Hashlock<Self::Api>>;
+
+    #[view(getOfferAccepters)]
+    #[storage_mapper("offerAccepters")]
+    fn offer_accepters(&self, offer_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getOfferApprovalPolicy)]
+    #[storage_mapper("offerApprovalPolicy")]
+    fn offer_approval_policy(&self, offer_id: u64) -> SingleValueMapper<ApprovalPolicy<Self::Api>>;
+
+    #[view(getOfferApprovals)]
+    #[storage_mapper("offerApprovals")]
+    fn offer_approvals(
+        &self,
+        offer_id: u64,
+        action: ApprovalAction,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getOfferCounterpartyCommitment)]
+    #[storage_mapper("offerCounterpartyCommitment")]
+    fn offer_counterparty_commitment(
+        &self,
+        offer_id: u64,
+    ) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+
+    #[event("hashlockClaimed")]
+    fn hashlock_claimed_event(&self, #[indexed] offer_id: u64, preimage: &ManagedBuffer);
 
     #[storage_mapper("lastOfferId")]
-    fn last_offer_id(&self) -> SingleValueMapper<u32>;
+    fn last_offer_id(&self) -> SingleValueMapper<u64>;
 }
@@ -0,0 +1,317 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::{claims, emergency};
+
+const MAX_LOAN_DURATION_SECONDS: u64 = 10 * 365 * 24 * 60 * 60;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct Loan<M: ManagedTypeApi> {
+    pub borrower: ManagedAddress<M>,
+    pub nft: TokenIdentifier<M>,
+    pub nonce: u64,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub repayment: BigUint<M>,
+    pub duration: u64,
+    pub lender: Option<ManagedAddress<M>>,
+    pub deadline: u64,
+}
+
+#[multiversx_sc::module]
+pub trait LoansModule: claims::ClaimsModule + emergency::EmergencyModule {
+    #[payable("*")]
+    #[endpoint(requestLoan)]
+    fn request_loan(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        repayment: BigUint,
+        duration: u64,
+    ) -> u32 {
+        self.require_no_emergency();
+
+        let payment = self.call_value().single_esdt();
+
+        require!(
+            payment.token_nonce > 0 && payment.amount == 1,
+            "ESDT is not an NFT"
+        );
+        require!(token.is_valid(), "Invalid loan token");
+        require!(amount > 0, "Loan amount must be positive");
+        require!(repayment >= amount, "Repayment must cover the loan amount");
+        require!(duration > 0, "Loan duration must be positive");
+        require!(
+            duration <= MAX_LOAN_DURATION_SECONDS,
+            "Loan duration is too long"
+        );
+
+        let borrower = self.blockchain().get_caller();
+
+        let loan_id = self.last_loan_id().update(|v| {
+            *v = match v.checked_add(1) {
+                Some(next) => next,
+                None => sc_panic!("Loan ID overflow"),
+            };
+
+            *v
+        });
+
+        self.borrower_loans(&borrower).insert(loan_id);
+
+        self.loans(loan_id).set(Loan {
+            borrower,
+            nft: payment.token_identifier,
+            nonce: payment.token_nonce,
+            token,
+            amount,
+            repayment,
+            duration,
+            lender: None,
+            deadline: 0,
+        });
+
+        loan_id
+    }
+
+    #[endpoint(cancelLoanRequest)]
+    fn cancel_loan_request(&self, loan_id: u32) {
+        let loan = self.require_loan(loan_id);
+
+        require!(
+            loan.borrower == self.blockchain().get_caller(),
+            "Only the borrower can cancel it"
+        );
+        require!(loan.lender.is_none(), "Loan is already funded");
+
+        self.remove_loan(loan_id, &loan);
+
+        self.send_payment(
+            &loan.borrower,
+            EsdtTokenPayment::new(loan.nft, loan.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    #[payable("*")]
+    #[endpoint(fundLoan)]
+    fn fund_loan(&self, loan_id: u32) {
+        let mut loan = self.require_loan(loan_id);
+
+        let caller = self.blockchain().get_caller();
+
+        require!(loan.borrower != caller, "Can not fund your own loan");
+        require!(loan.lender.is_none(), "Loan is already funded");
+
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+
+        require!(
+            token == loan.token && amount == loan.amount,
+            "Payment does not match the loan amount"
+        );
+
+        loan.lender = Some(caller);
+        loan.deadline = self.loan_deadline(loan.duration);
+
+        self.tx()
+            .to(&loan.borrower)
+            .egld_or_single_esdt(&loan.token, 0, &loan.amount)
+            .transfer();
+
+        self.loans(loan_id).set(loan);
+    }
+
+    #[payable("*")]
+    #[endpoint(repayLoan)]
+    fn repay_loan(&self, loan_id: u32) {
+        let loan = self.require_loan(loan_id);
+
+        require!(
+            loan.borrower == self.blockchain().get_caller(),
+            "Only the borrower can repay it"
+        );
+
+        let lender = match &loan.lender {
+            Some(lender) => lender.clone(),
+            None => sc_panic!("Loan is not funded"),
+        };
+
+        require!(
+            self.blockchain().get_block_timestamp() < loan.deadline,
+            "Loan deadline has passed"
+        );
+
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+
+        require!(
+            token == loan.token && amount == loan.repayment,
+            "Payment does not match the repayment amount"
+        );
+
+        self.remove_loan(loan_id, &loan);
+
+        // The lender may be a contract that no longer accepts transfers, which must not keep the
+        // borrower from getting the collateral back, so the repayment waits to be claimed.
+        self.lender_repayment_tokens(&lender)
+            .insert(loan.token.clone());
+        self.lender_repayments(&lender, &loan.token)
+            .update(|balance| *balance += &loan.repayment);
+
+        self.send_payment(
+            &loan.borrower,
+            EsdtTokenPayment::new(loan.nft, loan.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    #[endpoint(claimCollateral)]
+    fn claim_collateral(&self, loan_id: u32) {
+        let loan = self.require_loan(loan_id);
+
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            loan.lender.as_ref() == Some(&caller),
+            "Only the lender can claim the collateral"
+        );
+        require!(
+            self.blockchain().get_block_timestamp() >= loan.deadline,
+            "Loan is not overdue"
+        );
+
+        self.remove_loan(loan_id, &loan);
+
+        self.send_payment(
+            &caller,
+            EsdtTokenPayment::new(loan.nft, loan.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    #[endpoint(claimRepayment)]
+    fn claim_repayment(&self, token: EgldOrEsdtTokenIdentifier) {
+        let caller = self.blockchain().get_caller();
+
+        let amount = self.lender_repayments(&caller, &token).take();
+
+        require!(amount > 0, "Nothing to claim");
+
+        self.lender_repayment_tokens(&caller).swap_remove(&token);
+
+        self.tx()
+            .to(&caller)
+            .egld_or_single_esdt(&token, 0, &amount)
+            .transfer();
+    }
+
+    /// Funded loans are left in place, the borrower can still repay them and the lender can still
+    /// claim the collateral once they are overdue.
+    #[only_owner]
+    #[endpoint(emergencyReturnLoans)]
+    fn emergency_return_loans(&self, batch_size: u32) -> bool {
+        self.emergency_scan(
+            self.loans_emergency_cursor(),
+            self.last_loan_id().get(),
+            batch_size,
+            |loan_id| {
+                let loans_mapper = self.loans(loan_id);
+
+                if loans_mapper.is_empty() {
+                    return;
+                }
+
+                let loan = loans_mapper.get();
+
+                if loan.lender.is_some() {
+                    return;
+                }
+
+                self.remove_loan(loan_id, &loan);
+
+                self.send_payment(
+                    &loan.borrower,
+                    EsdtTokenPayment::new(loan.nft, loan.nonce, BigUint::from(1u64)),
+                );
+            },
+        )
+    }
+
+    #[view(getBorrowerLoans)]
+    fn get_borrower_loans(
+        &self,
+        borrower: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<u32, Loan<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+
+        for loan_id in self.borrower_loans(&borrower).iter() {
+            result.push(MultiValue2::from((loan_id, self.loans(loan_id).get())));
+        }
+
+        result
+    }
+
+    #[view(getLenderRepayments)]
+    fn get_lender_repayments(
+        &self,
+        lender: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+
+        for token in self.lender_repayment_tokens(&lender).iter() {
+            let amount = self.lender_repayments(&lender, &token).get();
+
+            result.push(MultiValue2::from((token, amount)));
+        }
+
+        result
+    }
+
+    fn loan_deadline(&self, duration: u64) -> u64 {
+        match self
+            .blockchain()
+            .get_block_timestamp()
+            .checked_add(duration)
+        {
+            Some(deadline) => deadline,
+            None => sc_panic!("Loan duration is too long"),
+        }
+    }
+
+    fn require_loan(&self, loan_id: u32) -> Loan<Self::Api> {
+        let loans_mapper = self.loans(loan_id);
+
+        require!(!loans_mapper.is_empty(), "Loan does not exist");
+
+        loans_mapper.get()
+    }
+
+    fn remove_loan(&self, loan_id: u32, loan: &Loan<Self::Api>) {
+        self.borrower_loans(&loan.borrower).swap_remove(&loan_id);
+        self.loans(loan_id).clear();
+    }
+
+    #[view(getLoan)]
+    #[storage_mapper("loans")]
+    fn loans(&self, loan_id: u32) -> SingleValueMapper<Loan<Self::Api>>;
+
+    #[storage_mapper("borrowerLoans")]
+    fn borrower_loans(&self, borrower: &ManagedAddress) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper("lenderRepayments")]
+    fn lender_repayments(
+        &self,
+        lender: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("lenderRepaymentTokens")]
+    fn lender_repayment_tokens(
+        &self,
+        lender: &ManagedAddress,
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getLoansEmergencyCursor)]
+    #[storage_mapper("loansEmergencyCursor")]
+    fn loans_emergency_cursor(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("lastLoanId")]
+    fn last_loan_id(&self) -> SingleValueMapper<u32>;
+}
@@ -0,0 +1,164 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+use multiversx_sc::storage::StorageKey;
+
+use crate::{Offer, OfferStatus};
+
+/// Offer layout of the deployed contract, before memos and explicit statuses were added.
+#[derive(TopEncode, TopDecode)]
+pub struct LegacyOffer<M: ManagedTypeApi> {
+    pub creator: ManagedAddress<M>,
+    pub nft: TokenIdentifier<M>,
+    pub nonce: u64,
+    pub wanted_nft: TokenIdentifier<M>,
+    pub wanted_nonce: u64,
+    pub wanted_address: ManagedAddress<M>,
+}
+
+impl<M: ManagedTypeApi> LegacyOffer<M> {
+    /// The deployed contract cleared offers once they were closed, so every stored one is open.
+    pub fn into_offer(self) -> Offer<M> {
+        Offer {
+            creator: self.creator,
+            nft: self.nft,
+            nonce: self.nonce,
+            wanted_nft: self.wanted_nft,
+            wanted_nonce: self.wanted_nonce,
+            wanted_address: self.wanted_address,
+            memo: ManagedBuffer::new(),
+            status: OfferStatus::Open,
+        }
+    }
+}
+
+#[derive(NestedEncode, Clone, Copy)]
+pub enum OfferIndex {
+    Created,
+    Wanted,
+}
+
+impl OfferIndex {
+    fn base_key(&self) -> &'static [u8] {
+        match self {
+            OfferIndex::Created => b"createdOffers",
+            OfferIndex::Wanted => b"wantedOffers",
+        }
+    }
+}
+
+/// Offer IDs used to be `u32`. The deployed contract only kept the offers and their created and
+/// wanted indexes under offer IDs, so those are declared here under their original keys and moved
+/// offer by offer after an upgrade. `migrateOfferIds` spends its batch size on both offer IDs and
+/// index entries.
+#[multiversx_sc::module]
+pub trait OfferIdMigrationModule {
+    fn start_offer_id_migration(&self, last_offer_id: u64) {
+        if self.offer_ids_migrated().get() {
+            return;
+        }
+
+        if last_offer_id == 0 {
+            self.offer_ids_migrated().set(true);
+        } else {
+            self.offer_id_migration_cursor().set(1);
+        }
+    }
+
+    fn finish_offer_id_migration(&self) {
+        self.offer_id_migration_cursor().clear();
+        self.offer_ids_migrated().set(true);
+    }
+
+    fn require_offer_ids_migrated(&self) {
+        require!(
+            self.offer_id_migration_cursor().is_empty(),
+            "Offer ID migration in progress"
+        );
+    }
+
+    /// The length and item keys of an ID set are the same for both widths, only the index keys
+    /// differ, so they are rewritten in place. Entries are moved in order and each one spends
+    /// from `budget`, which lets a large set span several calls. Returns whether the set is done.
+    fn migrate_offer_index(
+        &self,
+        index: OfferIndex,
+        address: &ManagedAddress,
+        budget: &mut u32,
+    ) -> bool {
+        let legacy = match index {
+            OfferIndex::Created => self.legacy_created_offers(address),
+            OfferIndex::Wanted => self.legacy_wanted_offers(address),
+        };
+
+        let len = legacy.len();
+
+        if len == 0
+            || !self
+                .offer_index_entry(index, address, &u64::from(legacy.get_by_index(len)))
+                .is_empty()
+        {
+            return true;
+        }
+
+        let cursor_mapper = self.offer_index_migration_cursor(index, address);
+        let mut position = core::cmp::max(cursor_mapper.get(), 1);
+
+        while position <= len && *budget > 0 {
+            let offer_id = legacy.get_by_index(position);
+
+            self.offer_index_entry(index, address, &offer_id).clear();
+            self.offer_index_entry(index, address, &u64::from(offer_id))
+                .set(position);
+
+            position += 1;
+            *budget -= 1;
+        }
+
+        if position > len {
+            cursor_mapper.clear();
+
+            return true;
+        }
+
+        cursor_mapper.set(position);
+
+        false
+    }
+
+    fn offer_index_entry<T: NestedEncode>(
+        &self,
+        index: OfferIndex,
+        address: &ManagedAddress,
+        offer_id: &T,
+    ) -> SingleValueMapper<usize> {
+        let mut key = StorageKey::new(index.base_key());
+        key.append_item(address);
+        key.append_bytes(b".index");
+        key.append_item(offer_id);
+
+        SingleValueMapper::new(key)
+    }
+
+    #[view(getOfferIdMigrationCursor)]
+    #[storage_mapper("offerIdMigrationCursor")]
+    fn offer_id_migration_cursor(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("offerIndexMigrationCursor")]
+    fn offer_index_migration_cursor(
+        &self,
+        index: OfferIndex,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("offerIdsMigrated")]
+    fn offer_ids_migrated(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("createdOffers")]
+    fn legacy_created_offers(&self, address: &ManagedAddress) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper("wantedOffers")]
+    fn legacy_wanted_offers(&self, address: &ManagedAddress) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper("offers")]
+    fn legacy_offers(&self, id: u32) -> SingleValueMapper<LegacyOffer<Self::Api>>;
+}
@@ -0,0 +1,43 @@
+use multiversx_sc::imports::*;
+
+#[multiversx_sc::module]
+pub trait OperatorsModule {
+    #[endpoint(approveOperator)]
+    fn approve_operator(&self, operator: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+
+        require!(caller != operator, "Can not approve yourself as operator");
+        require!(
+            self.operators(&caller).insert(operator),
+            "Operator is already approved"
+        );
+    }
+
+    #[endpoint(revokeOperator)]
+    fn revoke_operator(&self, operator: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            self.operators(&caller).swap_remove(&operator),
+            "Operator is not approved"
+        );
+    }
+
+    #[view(getOperators)]
+    fn get_operators(&self, address: ManagedAddress) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+
+        for operator in self.operators(&address).iter() {
+            result.push(operator);
+        }
+
+        result
+    }
+
+    fn can_act_for(&self, address: &ManagedAddress, caller: &ManagedAddress) -> bool {
+        address == caller || self.operators(address).contains(caller)
+    }
+
+    #[storage_mapper("operators")]
+    fn operators(&self, address: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
+}
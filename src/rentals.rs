@@ -0,0 +1,272 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::{claims, emergency};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct Rental<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub nft: TokenIdentifier<M>,
+    pub nonce: u64,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub price_per_period: BigUint<M>,
+    pub period: u64,
+    pub collateral: BigUint<M>,
+    pub renter: Option<ManagedAddress<M>>,
+    pub deadline: u64,
+}
+
+#[multiversx_sc::module]
+pub trait RentalsModule: claims::ClaimsModule + emergency::EmergencyModule {
+    #[payable("*")]
+    #[endpoint(listRental)]
+    fn list_rental(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        price_per_period: BigUint,
+        period: u64,
+        collateral: BigUint,
+    ) -> u32 {
+        self.require_no_emergency();
+
+        let payment = self.call_value().single_esdt();
+
+        require!(
+            payment.token_nonce > 0 && payment.amount == 1,
+            "ESDT is not an NFT"
+        );
+        require!(token.is_valid(), "Invalid rental token");
+        require!(period > 0, "Rental period must be positive");
+
+        let owner = self.blockchain().get_caller();
+
+        let rental_id = self.last_rental_id().update(|v| {
+            *v = match v.checked_add(1) {
+                Some(next) => next,
+                None => sc_panic!("Rental ID overflow"),
+            };
+
+            *v
+        });
+
+        self.owner_rentals(&owner).insert(rental_id);
+
+        self.rentals(rental_id).set(Rental {
+            owner,
+            nft: payment.token_identifier,
+            nonce: payment.token_nonce,
+            token,
+            price_per_period,
+            period,
+            collateral,
+            renter: None,
+            deadline: 0,
+        });
+
+        rental_id
+    }
+
+    #[endpoint(delistRental)]
+    fn delist_rental(&self, rental_id: u32) {
+        let rental = self.require_rental(rental_id);
+
+        require!(
+            rental.owner == self.blockchain().get_caller(),
+            "Only the owner can delist it"
+        );
+        require!(rental.renter.is_none(), "NFT is currently rented");
+
+        self.remove_rental(rental_id, &rental);
+
+        self.send_payment(
+            &rental.owner,
+            EsdtTokenPayment::new(rental.nft, rental.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn rent(&self, rental_id: u32, periods: u64) {
+        let mut rental = self.require_rental(rental_id);
+
+        let caller = self.blockchain().get_caller();
+
+        require!(rental.owner != caller, "Can not rent your own NFT");
+        require!(rental.renter.is_none(), "NFT is currently rented");
+        require!(periods > 0, "Rental periods must be positive");
+
+        let deadline = match rental.period.checked_mul(periods).and_then(|duration| {
+            self.blockchain()
+                .get_block_timestamp()
+                .checked_add(duration)
+        }) {
+            Some(deadline) => deadline,
+            None => sc_panic!("Rental duration is too long"),
+        };
+
+        let price = &rental.price_per_period * periods;
+
+        let (token, amount) = self.call_value().egld_or_single_fungible_esdt();
+
+        require!(
+            token == rental.token && amount == &price + &rental.collateral,
+            "Payment does not match the rental price and collateral"
+        );
+
+        rental.renter = Some(caller.clone());
+        rental.deadline = deadline;
+
+        if price > 0 {
+            self.tx()
+                .to(&rental.owner)
+                .egld_or_single_esdt(&rental.token, 0, &price)
+                .transfer();
+        }
+
+        self.send_payment(
+            &caller,
+            EsdtTokenPayment::new(rental.nft.clone(), rental.nonce, BigUint::from(1u64)),
+        );
+
+        self.rentals(rental_id).set(rental);
+    }
+
+    #[payable("*")]
+    #[endpoint(returnRental)]
+    fn return_rental(&self, rental_id: u32) {
+        let mut rental = self.require_rental(rental_id);
+
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            rental.renter.as_ref() == Some(&caller),
+            "Only the renter can return it"
+        );
+        require!(
+            self.blockchain().get_block_timestamp() < rental.deadline,
+            "Rental period has ended"
+        );
+
+        let payment = self.call_value().single_esdt();
+
+        require!(
+            payment.token_identifier == rental.nft
+                && payment.token_nonce == rental.nonce
+                && payment.amount == 1,
+            "NFT does not match"
+        );
+
+        if rental.collateral > 0 {
+            self.tx()
+                .to(&caller)
+                .egld_or_single_esdt(&rental.token, 0, &rental.collateral)
+                .transfer();
+        }
+
+        rental.renter = None;
+        rental.deadline = 0;
+
+        self.rentals(rental_id).set(rental);
+    }
+
+    #[endpoint(claimRentalCollateral)]
+    fn claim_rental_collateral(&self, rental_id: u32) {
+        let rental = self.require_rental(rental_id);
+
+        require!(
+            rental.owner == self.blockchain().get_caller(),
+            "Only the owner can claim the collateral"
+        );
+        require!(rental.renter.is_some(), "NFT is not rented");
+        require!(
+            self.blockchain().get_block_timestamp() >= rental.deadline,
+            "Rental period has not ended"
+        );
+
+        self.remove_rental(rental_id, &rental);
+
+        if rental.collateral > 0 {
+            self.tx()
+                .to(&rental.owner)
+                .egld_or_single_esdt(&rental.token, 0, &rental.collateral)
+                .transfer();
+        }
+    }
+
+    /// Rented NFTs are with their renters, so those rentals are left to be returned or to have
+    /// their collateral claimed.
+    #[only_owner]
+    #[endpoint(emergencyReturnRentals)]
+    fn emergency_return_rentals(&self, batch_size: u32) -> bool {
+        self.emergency_scan(
+            self.rentals_emergency_cursor(),
+            self.last_rental_id().get(),
+            batch_size,
+            |rental_id| {
+                let rentals_mapper = self.rentals(rental_id);
+
+                if rentals_mapper.is_empty() {
+                    return;
+                }
+
+                let rental = rentals_mapper.get();
+
+                if rental.renter.is_some() {
+                    return;
+                }
+
+                self.remove_rental(rental_id, &rental);
+
+                self.send_payment(
+                    &rental.owner,
+                    EsdtTokenPayment::new(rental.nft, rental.nonce, BigUint::from(1u64)),
+                );
+            },
+        )
+    }
+
+    #[view(getOwnerRentals)]
+    fn get_owner_rentals(
+        &self,
+        owner: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<u32, Rental<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+
+        for rental_id in self.owner_rentals(&owner).iter() {
+            result.push(MultiValue2::from((
+                rental_id,
+                self.rentals(rental_id).get(),
+            )));
+        }
+
+        result
+    }
+
+    fn require_rental(&self, rental_id: u32) -> Rental<Self::Api> {
+        let rentals_mapper = self.rentals(rental_id);
+
+        require!(!rentals_mapper.is_empty(), "Rental does not exist");
+
+        rentals_mapper.get()
+    }
+
+    fn remove_rental(&self, rental_id: u32, rental: &Rental<Self::Api>) {
+        self.owner_rentals(&rental.owner).swap_remove(&rental_id);
+        self.rentals(rental_id).clear();
+    }
+
+    #[view(getRental)]
+    #[storage_mapper("rentals")]
+    fn rentals(&self, rental_id: u32) -> SingleValueMapper<Rental<Self::Api>>;
+
+    #[storage_mapper("ownerRentals")]
+    fn owner_rentals(&self, owner: &ManagedAddress) -> UnorderedSetMapper<u32>;
+
+    #[view(getRentalsEmergencyCursor)]
+    #[storage_mapper("rentalsEmergencyCursor")]
+    fn rentals_emergency_cursor(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("lastRentalId")]
+    fn last_rental_id(&self) -> SingleValueMapper<u32>;
+}
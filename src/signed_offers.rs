@@ -0,0 +1,151 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::{claims, emergency};
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Debug)]
+pub struct SignedOffer<M: ManagedTypeApi> {
+    pub creator: ManagedAddress<M>,
+    pub nft: TokenIdentifier<M>,
+    pub nonce: u64,
+    pub wanted_nft: TokenIdentifier<M>,
+    pub wanted_nonce: u64,
+    pub counterparty: ManagedAddress<M>,
+    pub expiry: u64,
+    pub offer_nonce: u64,
+}
+
+#[multiversx_sc::module]
+pub trait SignedOffersModule: claims::ClaimsModule + emergency::EmergencyModule {
+    #[payable("*")]
+    #[endpoint(depositCustody)]
+    fn deposit_custody(&self) {
+        self.require_no_emergency();
+
+        let payments = self.call_value().all_esdt_transfers();
+
+        require!(!payments.is_empty(), "No NFTs sent");
+
+        let caller = self.blockchain().get_caller();
+
+        let mut custody_mapper = self.custody(&caller);
+
+        for payment in payments.iter() {
+            require!(
+                payment.token_nonce > 0 && payment.amount == 1,
+                "ESDT is not an NFT"
+            );
+
+            custody_mapper.insert(payment);
+        }
+    }
+
+    #[endpoint(withdrawCustody)]
+    fn withdraw_custody(&self, nft: TokenIdentifier, nonce: u64) {
+        let caller = self.blockchain().get_caller();
+
+        let payment = EsdtTokenPayment::new(nft, nonce, BigUint::from(1u64));
+
+        require!(
+            self.custody(&caller).swap_remove(&payment),
+            "NFT is not in custody"
+        );
+
+        self.send_payment(&caller, payment);
+    }
+
+    #[endpoint(cancelSignedOffer)]
+    fn cancel_signed_offer(&self, offer_nonce: u64) {
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            !self.used_offer_nonce(&caller, offer_nonce).get(),
+            "Signed offer nonce already used"
+        );
+
+        self.used_offer_nonce(&caller, offer_nonce).set(true);
+    }
+
+    #[payable("*")]
+    #[endpoint(fillSignedOffer)]
+    fn fill_signed_offer(&self, signed_offer: SignedOffer<Self::Api>, signature: ManagedBuffer) {
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            signed_offer.counterparty == caller,
+            "Can not fill this signed offer"
+        );
+        require!(
+            self.blockchain().get_block_timestamp() <= signed_offer.expiry,
+            "Signed offer has expired"
+        );
+
+        let used_nonce_mapper =
+            self.used_offer_nonce(&signed_offer.creator, signed_offer.offer_nonce);
+
+        require!(!used_nonce_mapper.get(), "Signed offer nonce already used");
+
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        message.append(
+            &self
+                .serializer()
+                .top_encode_to_managed_buffer(&signed_offer),
+        );
+
+        self.crypto().verify_ed25519(
+            signed_offer.creator.as_managed_buffer(),
+            &message,
+            &signature,
+        );
+
+        let payment = self.call_value().single_esdt();
+
+        require!(
+            payment.token_identifier == signed_offer.wanted_nft
+                && payment.token_nonce == signed_offer.wanted_nonce
+                && payment.amount == 1,
+            "NFT does not match"
+        );
+
+        let nft_payment =
+            EsdtTokenPayment::new(signed_offer.nft, signed_offer.nonce, BigUint::from(1u64));
+
+        require!(
+            self.custody(&signed_offer.creator)
+                .swap_remove(&nft_payment),
+            "NFT is not in custody"
+        );
+
+        used_nonce_mapper.set(true);
+
+        self.send_payment(&signed_offer.creator, payment);
+        self.send_payment(&caller, nft_payment);
+    }
+
+    #[view(getCustody)]
+    fn get_custody(&self, address: ManagedAddress) -> MultiValueEncoded<EsdtTokenPayment> {
+        let mut result = MultiValueEncoded::new();
+
+        for payment in self.custody(&address).iter() {
+            result.push(payment);
+        }
+
+        result
+    }
+
+    #[view(isSignedOfferNonceUsed)]
+    #[storage_mapper("usedOfferNonce")]
+    fn used_offer_nonce(
+        &self,
+        creator: &ManagedAddress,
+        offer_nonce: u64,
+    ) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("custody")]
+    fn custody(&self, address: &ManagedAddress) -> UnorderedSetMapper<EsdtTokenPayment>;
+}
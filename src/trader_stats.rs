@@ -0,0 +1,36 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct TraderStats {
+    pub offers_created: u64,
+    pub offers_accepted: u64,
+    pub offers_cancelled: u64,
+    pub offers_declined: u64,
+}
+
+#[multiversx_sc::module]
+pub trait TraderStatsModule {
+    #[view(getTraderStats)]
+    fn get_trader_stats(&self, address: ManagedAddress) -> TraderStats {
+        TraderStats {
+            offers_created: self.offers_created_count(&address).get(),
+            offers_accepted: self.offers_accepted_count(&address).get(),
+            offers_cancelled: self.offers_cancelled_count(&address).get(),
+            offers_declined: self.offers_declined_count(&address).get(),
+        }
+    }
+
+    #[storage_mapper("offersCreatedCount")]
+    fn offers_created_count(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("offersAcceptedCount")]
+    fn offers_accepted_count(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("offersCancelledCount")]
+    fn offers_cancelled_count(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("offersDeclinedCount")]
+    fn offers_declined_count(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,144 @@
+use multiversx_sc::derive_imports::*;
+use multiversx_sc::imports::*;
+
+use crate::{claims, emergency};
+
+const MAX_WISHLIST_ENTRIES: usize = 20;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, Debug)]
+pub struct WishlistEntry<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub nft: TokenIdentifier<M>,
+    pub nonce: u64,
+    pub wanted_nft: TokenIdentifier<M>,
+    pub wanted_nonce: u64,
+}
+
+#[multiversx_sc::module]
+pub trait WishlistModule: claims::ClaimsModule + emergency::EmergencyModule {
+    #[payable("*")]
+    #[endpoint(addWishlistEntry)]
+    fn add_wishlist_entry(&self, wanted_nft: TokenIdentifier, wanted_nonce: u64) -> u32 {
+        self.require_no_emergency();
+
+        let payment = self.call_value().single_esdt();
+
+        require!(
+            payment.token_nonce > 0 && payment.amount == 1,
+            "ESDT is not an NFT"
+        );
+        require!(wanted_nonce > 0, "Wanted ESDT is not an NFT");
+
+        let caller = self.blockchain().get_caller();
+
+        let mut wishlist_mapper = self.wishlist(&caller);
+
+        require!(
+            wishlist_mapper.len() < MAX_WISHLIST_ENTRIES,
+            "Too many wishlist entries"
+        );
+
+        let entry_id = self.last_wishlist_entry_id().update(|v| {
+            *v = match v.checked_add(1) {
+                Some(next) => next,
+                None => sc_panic!("Wishlist entry ID overflow"),
+            };
+
+            *v
+        });
+
+        wishlist_mapper.insert(entry_id);
+
+        self.wishlist_entries(entry_id).set(WishlistEntry {
+            owner: caller,
+            nft: payment.token_identifier,
+            nonce: payment.token_nonce,
+            wanted_nft,
+            wanted_nonce,
+        });
+
+        entry_id
+    }
+
+    #[endpoint(removeWishlistEntry)]
+    fn remove_wishlist_entry(&self, entry_id: u32) {
+        let entry_mapper = self.wishlist_entries(entry_id);
+
+        require!(!entry_mapper.is_empty(), "Wishlist entry does not exist");
+
+        let entry = entry_mapper.get();
+
+        require!(
+            entry.owner == self.blockchain().get_caller(),
+            "Only the wishlist owner can remove it"
+        );
+
+        self.take_wishlist_entry(entry_id, &entry);
+
+        self.send_payment(
+            &entry.owner,
+            EsdtTokenPayment::new(entry.nft, entry.nonce, BigUint::from(1u64)),
+        );
+    }
+
+    #[only_owner]
+    #[endpoint(emergencyReturnWishlist)]
+    fn emergency_return_wishlist(&self, batch_size: u32) -> bool {
+        self.emergency_scan(
+            self.wishlist_emergency_cursor(),
+            self.last_wishlist_entry_id().get(),
+            batch_size,
+            |entry_id| {
+                let entry_mapper = self.wishlist_entries(entry_id);
+
+                if entry_mapper.is_empty() {
+                    return;
+                }
+
+                let entry = entry_mapper.get();
+
+                self.take_wishlist_entry(entry_id, &entry);
+
+                self.send_payment(
+                    &entry.owner,
+                    EsdtTokenPayment::new(entry.nft, entry.nonce, BigUint::from(1u64)),
+                );
+            },
+        )
+    }
+
+    #[view(getWishlist)]
+    fn get_wishlist(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<u32, WishlistEntry<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+
+        for entry_id in self.wishlist(&address).iter() {
+            let entry = self.wishlist_entries(entry_id).get();
+
+            result.push(MultiValue2::from((entry_id, entry)));
+        }
+
+        result
+    }
+
+    fn take_wishlist_entry(&self, entry_id: u32, entry: &WishlistEntry<Self::Api>) {
+        self.wishlist(&entry.owner).swap_remove(&entry_id);
+        self.wishlist_entries(entry_id).clear();
+    }
+
+    #[storage_mapper("wishlist")]
+    fn wishlist(&self, address: &ManagedAddress) -> UnorderedSetMapper<u32>;
+
+    #[storage_mapper("wishlistEntries")]
+    fn wishlist_entries(&self, entry_id: u32) -> SingleValueMapper<WishlistEntry<Self::Api>>;
+
+    #[view(getWishlistEmergencyCursor)]
+    #[storage_mapper("wishlistEmergencyCursor")]
+    fn wishlist_emergency_cursor(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("lastWishlistEntryId")]
+    fn last_wishlist_entry_id(&self) -> SingleValueMapper<u32>;
+}
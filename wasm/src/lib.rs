@@ -5,9 +5,11 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            8
+// Upgrade:                              1
+// Endpoints:                          103
 // Async Callback (empty):               1
-// Total number of exported functions:  10
+// Promise callbacks:                    1
+// Total number of exported functions: 107
 
 #![no_std]
 
@@ -18,14 +20,111 @@ multiversx_sc_wasm_adapter::endpoints! {
     nft_escrow
     (
         init => init
+        upgrade => upgrade
         escrow => escrow
+        escrowFor => escrow_for
         cancel => cancel
+        cancelMany => cancel_many
+        cancelAll => cancel_all
+        transferOffer => transfer_offer
         accept => accept
+        claimWithPreimage => claim_with_preimage
+        approveOfferAction => approve_offer_action
+        approveArbitration => approve_arbitration
+        rejectArbitration => reject_arbitration
+        expireArbitration => expire_arbitration
+        decline => decline
+        emergencyWithdraw => emergency_withdraw
+        migrateOfferIds => migrate_offer_ids
         getCreatedOffers => get_created_offers
         getWantedOffers => get_wanted_offers
+        getOffer => get_offer
+        getOffers => get_offers
+        offerStatus => offer_status
+        getSolvencyReport => get_solvency_report
         created_offers => created_offers
         wanted_offers => wanted_offers
         offers => offers
+        getOfferDelivery => offer_delivery
+        getOfferWantedAttributes => offer_wanted_attributes
+        getOfferArbitration => offer_arbitration
+        getPendingAcceptance => pending_acceptance
+        getOfferHashlock => offer_hashlock
+        getOfferAccepters => offer_accepters
+        getOfferApprovalPolicy => offer_approval_policy
+        getOfferApprovals => offer_approvals
+        getOfferCounterpartyCommitment => offer_counterparty_commitment
+        setDeferredPayouts => set_deferred_payouts
+        claim => claim
+        getClaimablePayouts => get_claimable_payouts
+        hasDeferredPayouts => deferred_payouts
+        setOfferDeposit => set_offer_deposit
+        setOpenOffersLimits => set_open_offers_limits
+        depositBond => deposit_bond
+        withdrawBond => withdraw_bond
+        getOfferDeposit => offer_deposit
+        getMaxCreatedOffers => max_created_offers
+        getMaxWantedOffers => max_wanted_offers
+        getBondBalance => bond_balance
+        getLockedDeposit => locked_deposit
+        approveOperator => approve_operator
+        revokeOperator => revoke_operator
+        getOperators => get_operators
+        depositCustody => deposit_custody
+        withdrawCustody => withdraw_custody
+        cancelSignedOffer => cancel_signed_offer
+        fillSignedOffer => fill_signed_offer
+        getCustody => get_custody
+        isSignedOfferNonceUsed => used_offer_nonce
+        announceEmergency => announce_emergency
+        cancelEmergency => cancel_emergency
+        getEmergencyUnlockTimestamp => emergency_unlock_timestamp
+        getEmergencyCursor => emergency_cursor
+        getTraderStats => get_trader_stats
+        setSwapFee => set_swap_fee
+        setReferralRate => set_referral_rate
+        withdrawFees => withdraw_fees
+        claimReferralRewards => claim_referral_rewards
+        getSwapFee => swap_fee
+        getReferralRate => referral_rate
+        getReferralRewards => referral_rewards
+        getCollectedFees => collected_fees
+        getLockedFee => locked_fee
+        getOfferReferrer => offer_referrer
+        addWishlistEntry => add_wishlist_entry
+        removeWishlistEntry => remove_wishlist_entry
+        emergencyReturnWishlist => emergency_return_wishlist
+        getWishlist => get_wishlist
+        getWishlistEmergencyCursor => wishlist_emergency_cursor
+        placeBid => place_bid
+        cancelBid => cancel_bid
+        fillBid => fill_bid
+        emergencyReturnBids => emergency_return_bids
+        getBids => get_bids
+        getBidderBids => get_bidder_bids
+        getBidsEmergencyCursor => bids_emergency_cursor
+        requestLoan => request_loan
+        cancelLoanRequest => cancel_loan_request
+        fundLoan => fund_loan
+        repayLoan => repay_loan
+        claimCollateral => claim_collateral
+        claimRepayment => claim_repayment
+        emergencyReturnLoans => emergency_return_loans
+        getBorrowerLoans => get_borrower_loans
+        getLenderRepayments => get_lender_repayments
+        getLoan => loans
+        getLoansEmergencyCursor => loans_emergency_cursor
+        listRental => list_rental
+        delistRental => delist_rental
+        rent => rent
+        returnRental => return_rental
+        claimRentalCollateral => claim_rental_collateral
+        emergencyReturnRentals => emergency_return_rentals
+        getOwnerRentals => get_owner_rentals
+        getRental => rentals
+        getRentalsEmergencyCursor => rentals_emergency_cursor
+        getOfferIdMigrationCursor => offer_id_migration_cursor
+        delivery_callback => delivery_callback
     )
 }
 